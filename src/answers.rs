@@ -0,0 +1,110 @@
+use std::{fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::Part;
+
+/// Recorded answers of one day, read from `answers/dayNN.toml`, e.g.
+///
+/// ```toml
+/// part1 = 1227775554
+/// part2 = "4174379265"
+/// ```
+///
+/// Answers can be written as integers or strings; strings are needed for answers which do not
+/// fit in a TOML integer. A part without an answer is reported as missing.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+enum Answer {
+    Integer(i64),
+    String(String),
+}
+
+impl Answers {
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|error| error.to_string())
+    }
+
+    /// Load the answers from a file. A missing file means that no answer is recorded.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text)
+                .map_err(|error| format!("Invalid answers in {}: {}", path.display(), error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(format!("Unable to read {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<String> {
+        let answer = match part {
+            Part::Part1 => &self.part1,
+            Part::Part2 => &self.part2,
+        };
+        answer.as_ref().map(|answer| match answer {
+            Answer::Integer(answer) => answer.to_string(),
+            Answer::String(answer) => answer.clone(),
+        })
+    }
+
+    /// Compare the actual answer of a part with the recorded one.
+    pub fn verify(&self, part: Part, actual: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::Part;
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::from_toml("part1 = 1227775554").unwrap();
+        assert_eq!(answers.verify(Part::Part1, "1227775554"), Verdict::Pass);
+        assert_eq!(
+            answers.verify(Part::Part1, "1227775555"),
+            Verdict::Fail {
+                expected: "1227775554".to_string()
+            }
+        );
+        assert_eq!(answers.verify(Part::Part2, "0"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_string_answer() {
+        let answers = Answers::from_toml(r#"part2 = "18446744073709551615""#).unwrap();
+        assert_eq!(
+            answers.get(Part::Part2).as_deref(),
+            Some("18446744073709551615")
+        );
+        assert!(Answers::from_toml("part3 = 1").is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{Solution, params::Params, parse::ParseError};
+
+/// Wall time statistics of one phase over all iterations, in nanoseconds.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Timings {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timings {
+    fn measure<T>(iterations: usize, mut phase: impl FnMut() -> T) -> Self {
+        let mut samples: Vec<u64> = (0..iterations)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(phase());
+                start.elapsed().as_nanos() as u64
+            })
+            .collect();
+        samples.sort();
+
+        Timings {
+            min_ns: samples[0],
+            median_ns: samples[samples.len() / 2],
+            max_ns: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of the phases of one day.
+#[derive(Clone, Debug, Serialize)]
+pub struct BenchReport {
+    pub day: u8,
+    pub input: String,
+    pub iterations: usize,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+impl BenchReport {
+    /// Run each phase of `S` separately on the input for `iterations` times, which must not be zero.
+    /// `origin` names the input in the report.
+    pub fn measure<S: Solution>(
+        origin: &str,
+        input: &str,
+        iterations: usize,
+        params: &Params,
+    ) -> Result<Self, ParseError> {
+        let parsed = S::parse(input)?;
+
+        Ok(BenchReport {
+            day: S::DAY,
+            input: origin.to_string(),
+            iterations,
+            parse: Timings::measure(iterations, || S::parse(input)),
+            part1: Timings::measure(iterations, || S::part1(&parsed, params)),
+            part2: Timings::measure(iterations, || S::part2(&parsed, params)),
+        })
+    }
+
+    pub fn phases(&self) -> [(&'static str, Timings); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+/// Format the reports as a table, one row per phase.
+pub fn table(reports: &[BenchReport]) -> String {
+    let width = reports
+        .iter()
+        .map(|report| report.input.chars().count() + 2)
+        .max()
+        .unwrap_or_default()
+        .max(7);
+
+    let mut table = format!(
+        "{:<5}{:<width$}{:<8}{:>14}{:>14}{:>14}\n",
+        "Day", "Input", "Phase", "Min", "Median", "Max"
+    );
+    for report in reports {
+        for (phase, timings) in report.phases() {
+            table += &format!(
+                "{:<5}{:<width$}{:<8}{:>14}{:>14}{:>14}\n",
+                format!("{:02}", report.day),
+                report.input,
+                phase,
+                format!("{:.1?}", Duration::from_nanos(timings.min_ns)),
+                format!("{:.1?}", Duration::from_nanos(timings.median_ns)),
+                format!("{:.1?}", Duration::from_nanos(timings.max_ns)),
+            );
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BenchReport;
+    use crate::{Solution, day00::Day00, params::Params};
+
+    #[test]
+    fn test_measure() {
+        let report =
+            BenchReport::measure::<Day00>("<example>", "Hello", 5, &Params::default()).unwrap();
+        assert_eq!(report.day, Day00::DAY);
+        for (_, timings) in report.phases() {
+            assert!(timings.min_ns <= timings.median_ns);
+            assert!(timings.median_ns <= timings.max_ns);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::{fs, path::PathBuf, process::ExitCode, time::Instant};
+
+use advent_of_code_2025::{
+    Error, PUZZLES, Part, Puzzle,
+    answers::{Answers, Verdict},
+    bench, input_name,
+    params::{Params, parse_assignment},
+    puzzle, read_input,
+};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+/// Runner for the solutions of Advent of Code 2025.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day, or of all days, on the puzzle input.
+    Run(RunArgs),
+    /// Time the parse, part 1 and part 2 phases of one day, or of all days, separately.
+    Bench(BenchArgs),
+    /// Check the answers of every day on the puzzle inputs against the answers in `answers/`.
+    Verify(VerifyArgs),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct Selection {
+    /// Day of the puzzle.
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Select every implemented day, each on its default input.
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// Paths to the puzzle inputs, each solved separately, or `-` for the standard input.
+    /// Defaults to `input/dayNN.txt`.
+    #[arg(short, long, num_args = 1..)]
+    input: Vec<PathBuf>,
+
+    /// Override a parameter of the day, e.g. `--param connections=10`. Can be repeated.
+    #[arg(short = 'P', long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment, conflicts_with = "all")]
+    params: Vec<(String, String)>,
+}
+
+impl Selection {
+    fn puzzles(&self) -> Result<Vec<Puzzle>, String> {
+        match self.day {
+            Some(day) => puzzle(day)
+                .map(|puzzle| vec![puzzle])
+                .ok_or(format!("Day {day} is not implemented")),
+            None => Ok(PUZZLES.to_vec()),
+        }
+    }
+
+    fn inputs(&self, puzzle: &Puzzle) -> Vec<PathBuf> {
+        match self.input.is_empty() {
+            true => vec![puzzle.input_path()],
+            false => self.input.clone(),
+        }
+    }
+
+    /// Whether answers need to be labelled by their input.
+    fn labelled(&self) -> bool {
+        self.input.len() > 1
+    }
+
+    /// Call `task` with each selected puzzle, each of its inputs, the name of the input, and the
+    /// parameters. With a single puzzle and input the error is returned, otherwise errors are
+    /// reported and the remaining puzzles and inputs still run.
+    fn for_each(
+        &self,
+        mut task: impl FnMut(&Puzzle, &str, &str, &Params) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let puzzles = self.puzzles()?;
+
+        let mut runs = 0;
+        let mut failures = 0;
+        for puzzle in &puzzles {
+            let params = Params::with_overrides(puzzle.params(), &self.params)?;
+            for path in self.inputs(puzzle) {
+                let name = input_name(&path);
+                let result = read_input(&path)
+                    .map_err(|error| format!("Unable to read {}: {}", name, error))
+                    .and_then(|input| task(puzzle, &input, &name, &params));
+                runs += 1;
+                if let Err(error) = result {
+                    if !self.all && !self.labelled() {
+                        return Err(error);
+                    }
+                    eprintln!("error: {error}");
+                    failures += 1;
+                }
+            }
+        }
+
+        match failures {
+            0 => Ok(()),
+            _ => Err(format!("{failures} of {runs} runs failed")),
+        }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Part of the puzzle. Both parts are run if omitted.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Output format of the answers. In JSON, each answer is printed as an object on its own line.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// Answer of one part on one input, as printed in JSON. The answer is a string so that large
+/// integers survive JSON parsers using floating-point numbers.
+#[derive(Serialize)]
+struct AnswerRecord<'a> {
+    day: u8,
+    part: u8,
+    input: &'a str,
+    answer: String,
+    elapsed_ns: u64,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::Part1],
+            Some(2) => vec![Part::Part2],
+            _ => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of times each phase is run.
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Output format of the timings.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify the given day.
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let parts = args.parts();
+
+    let labelled = args.selection.labelled();
+
+    args.selection.for_each(|puzzle, input, origin, params| {
+        // A part without answer is reported, and the other parts still run, unlike on a malformed
+        // input.
+        let mut failures = 0;
+        for part in &parts {
+            let start = Instant::now();
+            let answer = match puzzle.solve(input, *part, params) {
+                Ok(answer) => answer,
+                Err(error @ Error::Parse(_)) => return Err(error.report(input, origin)),
+                Err(error) => {
+                    eprintln!("error: {}", error.report(input, origin));
+                    failures += 1;
+                    continue;
+                }
+            };
+            let elapsed = start.elapsed();
+
+            match (args.format, labelled) {
+                (Format::Json, _) => {
+                    let record = AnswerRecord {
+                        day: puzzle.day,
+                        part: part.number(),
+                        input: origin,
+                        answer,
+                        elapsed_ns: elapsed.as_nanos() as u64,
+                    };
+                    println!("{}", serde_json::to_string(&record).unwrap());
+                }
+                (Format::Text, true) => {
+                    println!("Day{:02} {} ({}): {}", puzzle.day, part, origin, answer)
+                }
+                (Format::Text, false) => println!("Day{:02} {}: {}", puzzle.day, part, answer),
+            }
+        }
+
+        match failures {
+            0 => Ok(()),
+            _ => Err(format!("{failures} of {} parts failed", parts.len())),
+        }
+    })
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let mut reports = Vec::new();
+    let result = args.selection.for_each(|puzzle, input, origin, params| {
+        let report = puzzle
+            .bench(origin, input, args.iterations as usize, params)
+            .map_err(|error| error.report(input, origin))?;
+        reports.push(report);
+        Ok(())
+    });
+
+    match args.format {
+        Format::Text => print!("{}", bench::table(&reports)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&reports).unwrap()),
+    }
+
+    result
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let puzzles = match args.day {
+        Some(day) => vec![puzzle(day).ok_or(format!("Day {day} is not implemented"))?],
+        None => PUZZLES.to_vec(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for puzzle in &puzzles {
+        let path = puzzle.input_path();
+        let Ok(input) = fs::read_to_string(&path) else {
+            println!("Day{:02}: missing input {}", puzzle.day, path.display());
+            missing += Part::ALL.len();
+            continue;
+        };
+        let answers = Answers::load(&puzzle.answers_path())?;
+        let params = Params::defaults(puzzle.params());
+
+        for part in Part::ALL {
+            let verdict = match puzzle.solve(&input, part, &params) {
+                Ok(actual) => {
+                    let verdict = answers.verify(part, &actual);
+                    println!("Day{:02} {}: {} {}", puzzle.day, part, actual, verdict);
+                    verdict
+                }
+                Err(error) => {
+                    println!("Day{:02} {}: FAIL ({})", puzzle.day, part, error);
+                    Verdict::Fail {
+                        expected: answers.get(part).unwrap_or_default(),
+                    }
+                }
+            };
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing => missing += 1,
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} answers do not match")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
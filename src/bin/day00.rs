@@ -1,57 +1,5 @@
-use std::{env, fs};
-
-fn parse(input: &str) -> &str {
-    // ----- START WORKING HERE -----
-    input
-}
-
-fn part1(input: &str) -> usize {
-    let input = parse(input);
-
-    // ----- START WORKING HERE -----
-    // Return number of bytes
-    input.len()
-}
-
-fn part2(input: &str) -> usize {
-    let input = parse(input);
-
-    // ----- START WORKING HERE -----
-    // Return number of characters
-    input.chars().count()
-}
-
-// =====================================================================
+use advent_of_code_2025::{day00::Day00, run};
 
 fn main() {
-    let part = env::args().nth(1).expect("Missing <part>");
-    let filename = env::args().nth(2).expect("Missing <input>");
-    let input = fs::read_to_string(filename).expect("Unable to read file");
-
-    match part.as_str() {
-        "part1" => println!("Part1: {}", part1(&input)),
-        "part2" => println!("Part2: {}", part2(&input)),
-        _ => panic!("Unknown part: {}", part)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{part1, part2};
-
-    const SHARED_INPUT: &str = r#"
-Hello👋🏻, World🌏
-"#;
-
-    #[test]
-    fn test_part1_example() {
-        let output = 24;
-        assert_eq!(part1(SHARED_INPUT.trim()), output);
-    }
-
-    #[test]
-    fn test_part2_example() {
-        let output = 15;
-        assert_eq!(part2(SHARED_INPUT.trim()), output);
-    }
+    run::<Day00>();
 }
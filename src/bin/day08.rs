@@ -1,158 +1,121 @@
-use std::{collections::BTreeMap, env, fs};
-
-type Junction = (u64, u64, u64);
-type Circuit = Vec<Junction>;
-
-fn distance_squared(junction_a: Junction, junction_b: Junction) -> u64 {
-    junction_a.0.abs_diff(junction_b.0) * junction_a.0.abs_diff(junction_b.0)
-        + junction_a.1.abs_diff(junction_b.1) * junction_a.1.abs_diff(junction_b.1)
-        + junction_a.2.abs_diff(junction_b.2) * junction_a.2.abs_diff(junction_b.2)
+use std::{path::PathBuf, process::ExitCode};
+
+use advent_of_code_2025::{
+    Solution,
+    day08::{Day08, Dendrogram, Junction, Metric, Pairs, SpanningTree, ties},
+    input_name,
+    params::{Params, parse_assignment},
+    read_input, run,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Solution of day 08, with `day08 partN FILE...`, and tools to inspect the circuits of the
+/// junction boxes.
+#[derive(Parser)]
+#[command(name = "day08")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn parse(input: &str) -> (Vec<(Junction, Junction)>, Vec<Circuit>) {
-    let junctions: Vec<Junction> = input
-        .lines()
-        .map(|line| {
-            let coordinates = line
-                .split(",")
-                .map(|num| num.parse::<u64>().unwrap())
-                .collect::<Vec<u64>>();
-            (coordinates[0], coordinates[1], coordinates[2])
-        })
-        .collect();
-
-    let mut distance_map = BTreeMap::new();
-    for i in 0..junctions.len() {
-        for j in i + 1..junctions.len() {
-            distance_map.insert(
-                distance_squared(junctions[i], junctions[j]),
-                (junctions[i], junctions[j]),
-            );
-        }
-    }
-    let sorted_pair: Vec<(Junction, Junction)> = distance_map
-        .iter()
-        .map(|(_, (junction_a, junction_b))| (*junction_a, *junction_b))
-        .collect();
-
-    let circuits: Vec<Circuit> = junctions
-        .into_iter()
-        .map(|junction| vec![junction])
-        .collect();
-
-    (sorted_pair, circuits)
+#[derive(Subcommand)]
+enum Command {
+    /// Print the minimum spanning tree of the junctions.
+    Mst {
+        #[arg(value_enum)]
+        format: TreeFormat,
+
+        #[command(flatten)]
+        junctions: JunctionArgs,
+    },
+    /// Print the merges of the single-linkage clustering of the junctions.
+    Dendrogram {
+        #[arg(value_enum)]
+        format: DendrogramFormat,
+
+        #[command(flatten)]
+        junctions: JunctionArgs,
+    },
+    /// Print the number of pairs of junctions at the same distance as the pair before them.
+    Ties {
+        #[command(flatten)]
+        junctions: JunctionArgs,
+    },
+    /// `part1` or `part2` and the inputs, parsed again by `run`.
+    #[command(external_subcommand)]
+    Part(#[allow(dead_code)] Vec<String>),
 }
 
-fn part1(input: &str) -> usize {
-    let (sorted_pair, mut circuits) = parse(input);
-
-    #[cfg(test)]
-    let shortest_pair = 10;
-
-    #[cfg(not(test))]
-    let shortest_pair = 1000;
-
-    for (junction_a, junction_b) in sorted_pair.iter().take(shortest_pair) {
-        let involved_circuit: Vec<Circuit> = circuits
-            .extract_if(.., |circuit| {
-                circuit.contains(junction_a) || circuit.contains(junction_b)
-            })
-            .collect();
-
-        let new_circuit =
-            involved_circuit
-                .iter()
-                .fold(Circuit::default(), |mut new_circult, circuit| {
-                    new_circult.extend(circuit);
-                    new_circult
-                });
-        circuits.push(new_circuit);
-    }
-
-    let mut sizes: Vec<usize> = circuits.iter().map(|circuit| circuit.len()).collect();
-    sizes.sort();
-    sizes.iter().rev().take(3).product()
+#[derive(Clone, Copy, ValueEnum)]
+enum TreeFormat {
+    Csv,
+    Dot,
 }
 
-fn part2(input: &str) -> u64 {
-    let (sorted_pair, mut circuits) = parse(input);
-
-    let mut product = 0;
-    for (junction_a, junction_b) in sorted_pair.iter() {
-        let involved_circuit: Vec<Circuit> = circuits
-            .extract_if(.., |circuit| {
-                circuit.contains(junction_a) || circuit.contains(junction_b)
-            })
-            .collect();
-
-        let new_circuit =
-            involved_circuit
-                .iter()
-                .fold(Circuit::default(), |mut new_circult, circuit| {
-                    new_circult.extend(circuit);
-                    new_circult
-                });
-        circuits.push(new_circuit);
-
-        if circuits.len() == 1 {
-            product = junction_a.0 * junction_b.0;
-            break;
-        }
-    }
-
-    product
+#[derive(Clone, Copy, ValueEnum)]
+enum DendrogramFormat {
+    Text,
+    Newick,
 }
 
-// =====================================================================
+#[derive(Args)]
+struct JunctionArgs {
+    /// Path to the puzzle input, or `-` for the standard input.
+    input: PathBuf,
 
-fn main() {
-    let part = env::args().nth(1).expect("Missing <part>");
-    let filename = env::args().nth(2).expect("Missing <input>");
-    let input = fs::read_to_string(filename).expect("Unable to read file");
+    /// Override a parameter of the day, e.g. `--param metric=manhattan`. Can be repeated.
+    #[arg(short = 'P', long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+    params: Vec<(String, String)>,
+}
 
-    match part.as_str() {
-        "part1" => println!("Part1: {}", part1(&input)),
-        "part2" => println!("Part2: {}", part2(&input)),
-        _ => panic!("Unknown part: {}", part),
+impl JunctionArgs {
+    /// The junctions of the input and the metric of the parameters.
+    fn load(&self) -> Result<(Vec<Junction>, Metric), String> {
+        let name = input_name(&self.input);
+        let input = read_input(&self.input)
+            .map_err(|error| format!("Unable to read {}: {}", name, error))?;
+        let junctions = Day08::parse(&input).map_err(|error| error.report(&input, &name))?;
+        let params = Params::with_overrides(Day08::PARAMS, &self.params)?;
+        Ok((junctions, params.get("metric")))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{part1, part2};
-
-    const SHARED_INPUT: &str = r#"
-162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-"#;
-
-    #[test]
-    fn test_part1_example() {
-        let output = 40;
-        assert_eq!(part1(SHARED_INPUT.trim()), output);
-    }
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Mst { format, junctions } => junctions.load().map(|(junctions, metric)| {
+            let tree = SpanningTree::new(&junctions, metric);
+            match format {
+                TreeFormat::Csv => tree.to_csv(),
+                TreeFormat::Dot => tree.to_dot(),
+            }
+        }),
+        Command::Dendrogram { format, junctions } => junctions.load().map(|(junctions, metric)| {
+            let dendrogram = Dendrogram::new(&junctions, metric);
+            match format {
+                DendrogramFormat::Text => dendrogram.to_text(),
+                DendrogramFormat::Newick => dendrogram.to_newick() + "\n",
+            }
+        }),
+        Command::Ties { junctions } => junctions.load().map(|(junctions, metric)| {
+            let ties = ties(Pairs::new(&junctions, metric));
+            format!("{ties} pairs tied at equal distance\n")
+        }),
+        Command::Part(_) => {
+            run::<Day08>();
+            return ExitCode::SUCCESS;
+        }
+    };
 
-    #[test]
-    fn test_part2_example() {
-        let output = 25272;
-        assert_eq!(part2(SHARED_INPUT.trim()), output);
+    match result {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
     }
 }
@@ -1,173 +1,177 @@
-use std::{env, fs, iter::Sum};
-
-use good_lp::{Expression, Solution, SolverModel, constraint, default_solver, variables};
-
-// The solution requires external integer programming solver. It uses COIN-OR Branch-and-Cut solver
-// (https://github.com/coin-or/Cbc). To install it, you can run the following commands:
-//
-// Arch Linux:
-// $ pacman -S coin-or-cbc
-//
-// Defian:
-// $ sudo apt-get install  coinor-cbc coinor-libcbc-dev
-
-type Light = u8;
-type Button = Vec<usize>;
-type Joltage = u32;
-
-struct Machine {
-    lights: Vec<Light>,
-    buttons: Vec<Button>,
-    joltages: Vec<Joltage>,
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use advent_of_code_2025::{
+    Part, Solution,
+    day10::{Day10, Machine, explain, model},
+    input_name,
+    params::{Params, parse_assignment},
+    read_input, run,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Solution of day 10, with `day10 partN FILE...`, and tools to check the presses of the buttons.
+#[derive(Parser)]
+#[command(name = "day10")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn parse(input: &str) -> Vec<Machine> {
-    input
-        .lines()
-        .map(|line| {
-            let section: Vec<&str> = line.split_ascii_whitespace().collect();
-            let lights: Vec<Light> = section
-                .first()
-                .unwrap()
-                .trim_matches(['[', ']'])
-                .chars()
-                .map(|light| match light {
-                    '.' => 0,
-                    '#' => 1,
-                    _ => panic!(),
-                })
-                .collect();
-            let buttons: Vec<Button> = section
-                .iter()
-                .take(section.len() - 1)
-                .skip(1)
-                .map(|button_str| {
-                    button_str
-                        .trim_matches(['(', ')'])
-                        .split(",")
-                        .map(|num| num.parse().unwrap())
-                        .collect::<Button>()
-                })
-                .collect();
-            let joltages: Vec<Joltage> = section
-                .last()
-                .unwrap()
-                .trim_matches(['{', '}'])
-                .split(",")
-                .map(|num| num.parse().unwrap())
-                .collect();
-
-            Machine {
-                lights,
-                buttons,
-                joltages,
-            }
-        })
-        .collect()
+#[derive(Subcommand)]
+enum Command {
+    /// Print the presses of each button of every machine, and check that they reproduce the
+    /// lights or joltages.
+    Explain {
+        /// Part of the puzzle.
+        #[arg(value_enum)]
+        part: PartArg,
+
+        #[command(flatten)]
+        machines: MachineArgs,
+
+        /// Override a parameter of the day, e.g. `--param solver=native`. Can be repeated.
+        #[arg(short = 'P', long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+        params: Vec<(String, String)>,
+    },
+    /// Write the model of every machine for the part to `DIRECTORY/lineL_partN.lp` or `.mps`,
+    /// where `L` is the line of the machine.
+    Export {
+        /// Part of the puzzle.
+        #[arg(value_enum)]
+        part: PartArg,
+
+        #[arg(value_enum)]
+        format: ModelFormat,
+
+        #[command(flatten)]
+        machines: MachineArgs,
+
+        /// Directory of the models, created if missing.
+        directory: PathBuf,
+    },
+    /// `part1` or `part2` and the inputs, parsed again by `run`.
+    #[command(external_subcommand)]
+    Part(#[allow(dead_code)] Vec<String>),
 }
 
-fn part1(input: &str) -> u64 {
-    let machines = parse(input);
-
-    machines
-        .iter()
-        .map(|machine| {
-            variables! {
-                vars:
-                    0 <= x[machine.buttons.len()] (integer);
-                    0 <= multiplier[machine.lights.len()] (integer);
-            }
-
-            let mut lp = vars
-                .minimise(Expression::sum(x.iter()))
-                .using(default_solver);
-            let light_indices = 0..machine.lights.len();
-            for light_index in light_indices {
-                let lin_comb = Expression::sum(
-                    machine
-                        .buttons
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, button)| button.contains(&light_index))
-                        .map(|(column, _)| x[column]),
-                );
-                lp = lp.with(constraint!(
-                    lin_comb == 2 * multiplier[light_index] + machine.lights[light_index]
-                ))
-            }
-
-            let solution = lp.solve().unwrap();
-            solution.eval(Expression::sum(x.iter())).round() as u64
-        })
-        .sum()
+#[derive(Clone, Copy, ValueEnum)]
+enum PartArg {
+    Part1,
+    Part2,
 }
 
-fn part2(input: &str) -> u64 {
-    let machines = parse(input);
-
-    machines
-        .iter()
-        .map(|machine| {
-            variables! {
-                vars:
-                    0 <= x[machine.buttons.len()] (integer);
-            }
-
-            let mut lp = vars
-                .minimise(Expression::sum(x.iter()))
-                .using(default_solver);
-            let joltage_indices = 0..machine.lights.len();
-            for joltage_index in joltage_indices {
-                let lin_comb = Expression::sum(
-                    machine
-                        .buttons
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, button)| button.contains(&joltage_index))
-                        .map(|(column, _)| x[column]),
-                );
-                lp = lp.with(constraint!(lin_comb == machine.joltages[joltage_index]))
-            }
-
-            let solution = lp.solve().unwrap();
-            solution.eval(Expression::sum(x.iter())).round() as u64
-        })
-        .sum()
+impl From<PartArg> for Part {
+    fn from(part: PartArg) -> Self {
+        match part {
+            PartArg::Part1 => Part::Part1,
+            PartArg::Part2 => Part::Part2,
+        }
+    }
 }
 
-// =====================================================================
-
-fn main() {
-    let part = env::args().nth(1).expect("Missing <part>");
-    let filename = env::args().nth(2).expect("Missing <input>");
-    let input = fs::read_to_string(filename).expect("Unable to read file");
+#[derive(Clone, Copy, ValueEnum)]
+enum ModelFormat {
+    Lp,
+    Mps,
+}
 
-    match part.as_str() {
-        "part1" => println!("Part1: {}", part1(&input)),
-        "part2" => println!("Part2: {}", part2(&input)),
-        _ => panic!("Unknown part: {}", part),
+impl ModelFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ModelFormat::Lp => "lp",
+            ModelFormat::Mps => "mps",
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{part1, part2};
+#[derive(Args)]
+struct MachineArgs {
+    /// Path to the puzzle input, or `-` for the standard input.
+    input: PathBuf,
+}
 
-    const SHARED_INPUT: &str = r#"
-[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
-"#;
+impl MachineArgs {
+    /// The machines of the input.
+    fn load(&self) -> Result<Vec<Machine>, String> {
+        let name = input_name(&self.input);
+        let input = read_input(&self.input)
+            .map_err(|error| format!("Unable to read {}: {}", name, error))?;
+        Day10::parse(&input).map_err(|error| error.report(&input, &name))
+    }
+}
+
+fn explain_machines(
+    part: Part,
+    args: &MachineArgs,
+    params: &[(String, String)],
+) -> Result<(), String> {
+    let machines = args.load()?;
+    let params = Params::with_overrides(Day10::PARAMS, params)?;
+    let report = match part {
+        Part::Part1 => Day10::part1(&machines, &params),
+        Part::Part2 => Day10::part2(&machines, &params)?,
+    };
+    print!("{}", explain(&machines, &report, part));
+    Ok(())
+}
 
-    #[test]
-    fn test_part1_example() {
-        let output = 7;
-        assert_eq!(part1(SHARED_INPUT.trim()), output);
+fn export(
+    part: Part,
+    format: ModelFormat,
+    args: &MachineArgs,
+    directory: &Path,
+) -> Result<(), String> {
+    let machines = args.load()?;
+    fs::create_dir_all(directory)
+        .map_err(|error| format!("Unable to create {}: {}", directory.display(), error))?;
+    for machine in &machines {
+        let model = model(machine, part);
+        let contents = match format {
+            ModelFormat::Lp => model.to_lp(),
+            ModelFormat::Mps => model.to_mps(),
+        };
+        let path = directory.join(format!(
+            "line{}_part{}.{}",
+            machine.line,
+            part.number(),
+            format.extension()
+        ));
+        fs::write(&path, contents)
+            .map_err(|error| format!("Unable to write {}: {}", path.display(), error))?;
     }
+    Ok(())
+}
 
-    #[test]
-    fn test_part2_example() {
-        let output = 33;
-        assert_eq!(part2(SHARED_INPUT.trim()), output);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Explain {
+            part,
+            machines,
+            params,
+        } => explain_machines(part.into(), &machines, &params),
+        Command::Export {
+            part,
+            format,
+            machines,
+            directory,
+        } => export(part.into(), format, &machines, &directory),
+        Command::Part(_) => {
+            run::<Day10>();
+            return ExitCode::SUCCESS;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
     }
 }
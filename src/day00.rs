@@ -0,0 +1,50 @@
+use crate::{Solution, params::Params, parse::ParseError};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Parsed = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        // ----- START WORKING HERE -----
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        // ----- START WORKING HERE -----
+        // Return number of bytes
+        input.len()
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        // ----- START WORKING HERE -----
+        // Return number of characters
+        input.chars().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day00;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+Hello👋🏻, World🌏
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 24;
+        assert_eq!(Day00::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 15;
+        assert_eq!(Day00::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+}
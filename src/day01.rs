@@ -0,0 +1,104 @@
+use crate::{
+    Solution,
+    params::Params,
+    parse::{ParseError, Source},
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input
+            .lines()
+            .map(|line| {
+                if let Some(num) = line.strip_prefix("L") {
+                    source.number::<i32>(num).map(|num| -num)
+                } else if let Some(num) = line.strip_prefix("R") {
+                    source.number::<i32>(num)
+                } else {
+                    Err(source.error(line, "unknown rotation, expected `L` or `R`"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let mut dial = 50;
+        let mut count = 0;
+        for rotation in input {
+            dial = (dial + rotation).rem_euclid(100);
+            if dial == 0 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let mut dial: i32 = 50;
+        let mut count = 0;
+        for &rotation in input {
+            count += if rotation > 0 {
+                // `R` rotation:
+                // Count the number of times of crossing multiples of 100.
+                (dial + rotation) / 100
+            } else {
+                // `L` rotation:
+                // Label 1 as 99, 2 as 98, ..., 99 as 1, but 0 as 0. New `L` rotation is toward
+                // higher numbers. We can then apply the same logic in the case of `R` rotation.
+                let reverse_dial = (100 - dial) % 100;
+                (reverse_dial - rotation) / 100
+            };
+            dial = (dial + rotation).rem_euclid(100);
+        }
+
+        count as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day01;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 3;
+        assert_eq!(Day01::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 6;
+        assert_eq!(Day01::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day01::parse("L68\nL30\nX48").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 3, 1));
+        assert_eq!(error.text, "X48");
+    }
+}
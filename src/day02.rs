@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use crate::{
+    Solution,
+    params::Params,
+    parse::{ParseError, Source},
+};
+
+pub struct Day02;
+
+fn collect_invalid_ids(invalid_ids: &mut HashSet<u64>, times: u32, start: u64, end: u64) {
+    for width in 1..=10u32 / times {
+        let multiplier: u64 = (0..times).map(|t| 10u64.pow(width * t)).sum();
+        let halves = 10u64.pow(width - 1)..=10u64.pow(width) - 1;
+        for half in halves {
+            let invalid_id = multiplier * half;
+            if start <= invalid_id && invalid_id <= end {
+                invalid_ids.insert(invalid_id);
+            }
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input
+            .trim()
+            .split(",")
+            .map(|range| {
+                let (start, end) = source.split_once(range, "-")?;
+                Ok((source.number::<u64>(start)?, source.number::<u64>(end)?))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let mut invalid_ids = HashSet::<u64>::new();
+        input
+            .iter()
+            .for_each(|(start, end)| collect_invalid_ids(&mut invalid_ids, 2, *start, *end));
+        invalid_ids.iter().sum()
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let mut invalid_ids = HashSet::<u64>::new();
+        for times in 2..=10 {
+            input.iter().for_each(|(start, end)| {
+                collect_invalid_ids(&mut invalid_ids, times, *start, *end)
+            });
+        }
+        invalid_ids.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day02;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 1227775554;
+        assert_eq!(Day02::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 4174379265;
+        assert_eq!(Day02::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+}
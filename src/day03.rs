@@ -0,0 +1,100 @@
+use crate::{
+    Solution,
+    params::Params,
+    parse::{ParseError, Source, chars},
+};
+
+pub struct Day03;
+
+fn max_position(digits: &[u64], skip_last: usize) -> (u64, usize) {
+    let max_digit = digits[..digits.len() - skip_last].iter().max().unwrap();
+    let position = digits.iter().position(|digit| digit == max_digit).unwrap();
+    (*max_digit, position)
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Vec<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input
+            .lines()
+            .map(|line| {
+                if line.len() < 12 {
+                    return Err(source.error(line, "expected a bank of at least 12 batteries"));
+                }
+                chars(line)
+                    .map(|(digit, span)| {
+                        digit
+                            .to_digit(10)
+                            .map(|digit| digit as u64)
+                            .ok_or_else(|| source.error(span, "expected a digit"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        input
+            .iter()
+            .map(|bank| {
+                let (first_digit, position) = max_position(bank, 1);
+                let (second_digit, _) = max_position(&bank[position + 1..], 0);
+                first_digit * 10 + second_digit
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        input
+            .iter()
+            .map(|bank| {
+                let mut bank: &[u64] = bank;
+                let mut joltage = 0u64;
+                for round in (0..12).rev() {
+                    let (digit, position) = max_position(bank, round);
+                    joltage = joltage * 10 + digit;
+                    bank = &bank[position + 1..];
+                }
+                joltage
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day03;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 357;
+        assert_eq!(Day03::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 3121910778619;
+        assert_eq!(Day03::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day03::parse("987654321111111\n1").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    Solution,
+    grid::Grid,
+    params::Params,
+    parse::{ParseError, Source},
+};
+
+pub struct Day04;
+
+/// Rolls of paper, with the number of neighbouring rolls, or `None` for an empty position.
+type Map = Grid<Option<u8>>;
+
+fn remove(map: Map) -> (Map, usize) {
+    let mut new_map = map.clone();
+    let mut removed = 0;
+
+    for position in map.positions() {
+        if map[position].is_some_and(|count| count < 4) {
+            new_map[position] = None;
+            removed += 1;
+            for neighbour in map.neighbours8(position) {
+                new_map[neighbour] = new_map[neighbour].map(|count| count.saturating_sub(1));
+            }
+        }
+    }
+
+    (new_map, removed)
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        let mut map: Map = Grid::parse(&source, |c, span| match c {
+            '@' => Ok(Some(0)),
+            '.' => Ok(None),
+            _ => Err(source.error(span, "unknown cell, expected `@` or `.`")),
+        })?;
+
+        for position in map.positions() {
+            if map[position].is_some() {
+                for neighbour in map.neighbours8(position) {
+                    map[neighbour] = map[neighbour].map(|count| count + 1);
+                }
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let (_, removed) = remove(input.clone());
+        removed
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let mut map = input.clone();
+        let mut removed;
+
+        let mut total_removed = 0;
+        loop {
+            (map, removed) = remove(map);
+            total_removed += removed;
+            if removed == 0 {
+                break;
+            }
+        }
+
+        total_removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day04;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 13;
+        assert_eq!(Day04::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 43;
+        assert_eq!(Day04::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    Solution,
+    params::Params,
+    parse::{ParseError, Source},
+    range_set::RangeSet,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = (RangeSet, Vec<u64>);
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        let (ranges, ingredients) = input.split_once("\n\n").ok_or_else(|| {
+            source.error_after(input, "missing blank line between ranges and ingredients")
+        })?;
+
+        let fresh = ranges
+            .trim()
+            .split("\n")
+            .map(|range| {
+                let (start, end) = source.split_once(range, "-")?;
+                Ok(source.number(start)?..=source.number(end)?)
+            })
+            .collect::<Result<_, _>>()?;
+        let ingredients = ingredients
+            .trim()
+            .split("\n")
+            .map(|ingredient| source.number(ingredient))
+            .collect::<Result<_, _>>()?;
+
+        Ok((fresh, ingredients))
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let (fresh, ingredients) = input;
+
+        ingredients
+            .iter()
+            .filter(|&&ingredient| fresh.contains(ingredient))
+            .count()
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let (fresh, _) = input;
+        fresh.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day05;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 3;
+        assert_eq!(Day05::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 14;
+        assert_eq!(Day05::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+}
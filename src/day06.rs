@@ -0,0 +1,174 @@
+use crate::{
+    Solution,
+    grid::Grid,
+    params::Params,
+    parse::{ParseError, Source, chars},
+};
+
+pub struct Day06;
+
+enum Operation {
+    Addition,
+    Multiplication,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Grid<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        source.rectangle()?;
+
+        let height = input.lines().count();
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                chars(line)
+                    .map(|(cell, span)| match cell {
+                        ' ' => Ok(cell),
+                        '+' | '*' if i == height - 1 => Ok(cell),
+                        _ if i == height - 1 => {
+                            Err(source.error(span, "unknown operation, expected `+` or `*`"))
+                        }
+                        _ if cell.is_ascii_digit() => Ok(cell),
+                        _ => Err(source.error(span, "expected a digit")),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        // The last row holds one operation per problem, and every other row one number per problem.
+        let lines: Vec<&str> = input.lines().collect();
+        let (operations, numbers) = lines.split_last().expect("Grid checked to be non-empty");
+        if let Some(token) = operations.split_whitespace().find(|token| token.len() > 1) {
+            return Err(source.error(token, "expected one operation per problem"));
+        }
+        let problems = operations.split_whitespace().count();
+        for line in numbers {
+            let count = line.split_whitespace().count();
+            if count != problems {
+                return Err(source.error(
+                    line,
+                    format!("expected {problems} numbers, one per operation, found {count}"),
+                ));
+            }
+        }
+
+        Ok(Grid::from_rows(rows).expect("Rows checked to be of equal width"))
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let lines: Vec<String> = input.rows().map(|row| row.iter().collect()).collect();
+        let grid = Grid::from_rows(
+            lines
+                .iter()
+                .map(|line| line.split_whitespace().collect())
+                .collect(),
+        )
+        .unwrap();
+
+        let mut grand_total = 0;
+        for column in grid.columns() {
+            let column: Vec<&str> = column.copied().collect();
+            grand_total += match *column.last().unwrap() {
+                "+" => column
+                    .iter()
+                    .take(column.len() - 1)
+                    .map(|num| num.parse::<u64>().unwrap())
+                    .sum::<u64>(),
+                "*" => column
+                    .iter()
+                    .take(column.len() - 1)
+                    .map(|num| num.parse::<u64>().unwrap())
+                    .product::<u64>(),
+                _ => panic!(),
+            };
+        }
+
+        grand_total
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let grid = input.transpose();
+
+        let mut grand_total = 0;
+        let mut total = 0;
+        let mut operation = Operation::Addition;
+        for column in grid.rows() {
+            if column.iter().all(|cell| *cell == ' ') {
+                grand_total += total;
+                continue;
+            }
+
+            match column.last().unwrap() {
+                '+' => {
+                    operation = Operation::Addition;
+                    total = 0;
+                }
+                '*' => {
+                    operation = Operation::Multiplication;
+                    total = 1;
+                }
+                _ => (),
+            }
+
+            let num = column
+                .iter()
+                .filter(|cell| cell.is_ascii_digit())
+                .fold(0u64, |num, digit| {
+                    num * 10 + digit.to_digit(10).unwrap() as u64
+                });
+            match operation {
+                Operation::Addition => total += num,
+                Operation::Multiplication => total *= num,
+            }
+        }
+        grand_total += total;
+
+        grand_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day06;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 4277556;
+        assert_eq!(
+            Day06::solve_part1(&SHARED_INPUT[1..SHARED_INPUT.len() - 1]),
+            Ok(output)
+        );
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 3263827;
+        assert_eq!(
+            Day06::solve_part2(&SHARED_INPUT[1..SHARED_INPUT.len() - 1]),
+            Ok(output)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day06::parse("12 \n+ *").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Day06::parse("1 2\n+* ").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
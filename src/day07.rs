@@ -0,0 +1,152 @@
+use std::ops;
+
+use crate::{
+    Solution,
+    grid::Grid,
+    params::Params,
+    parse::{ParseError, Source},
+};
+
+pub struct Day07;
+
+#[derive(Clone, Copy)]
+pub enum Position {
+    Space,
+    Splitter,
+    Beam(u64),
+}
+
+pub type Map = Grid<Position>;
+
+impl Position {
+    fn is_splitter(&self) -> bool {
+        match self {
+            Position::Space => false,
+            Position::Splitter => true,
+            Position::Beam(_) => false,
+        }
+    }
+
+    fn is_beam(&self) -> bool {
+        match self {
+            Position::Space => false,
+            Position::Splitter => false,
+            Position::Beam(_) => true,
+        }
+    }
+}
+
+impl ops::Add<Position> for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Position) -> Self::Output {
+        match (self, rhs) {
+            (Position::Space, Position::Space) => Position::Space,
+            (Position::Space, Position::Splitter) => Position::Space,
+            (Position::Space, Position::Beam(rhs)) => Position::Beam(rhs),
+            (Position::Splitter, Position::Space) => Position::Splitter,
+            (Position::Splitter, Position::Splitter) => Position::Splitter,
+            (Position::Splitter, Position::Beam(_)) => Position::Splitter,
+            (Position::Beam(lhs), Position::Space) => Position::Beam(lhs),
+            (Position::Beam(lhs), Position::Splitter) => Position::Beam(lhs),
+            (Position::Beam(lhs), Position::Beam(rhs)) => Position::Beam(lhs + rhs),
+        }
+    }
+}
+
+fn emit(map: &mut Map) -> u64 {
+    let mut total_split = 0;
+    for i in 0..map.height() - 1 {
+        for j in 0..map.width() {
+            let beam = map[(i, j)];
+            if !beam.is_beam() {
+                continue;
+            }
+            map[(i + 1, j)] = map[(i + 1, j)] + beam;
+            if map[(i + 1, j)].is_splitter() {
+                total_split += 1;
+                for neighbour in [j.checked_sub(1), Some(j + 1)] {
+                    if let Some(position) = neighbour.and_then(|j| map.get_mut((i + 1, j))) {
+                        *position = *position + beam;
+                    }
+                }
+            }
+        }
+    }
+
+    total_split
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        Grid::parse(&source, |c, span| match c {
+            '.' => Ok(Position::Space),
+            '^' => Ok(Position::Splitter),
+            'S' => Ok(Position::Beam(1)),
+            _ => Err(source.error(span, "unknown cell, expected `.`, `^` or `S`")),
+        })
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let mut map = input.clone();
+        emit(&mut map)
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let mut map = input.clone();
+        emit(&mut map);
+        map.row(map.height() - 1)
+            .iter()
+            .map(|position| match position {
+                Position::Space => 0,
+                Position::Splitter => 0,
+                Position::Beam(beam) => *beam,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day07;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 21;
+        assert_eq!(Day07::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 40;
+        assert_eq!(Day07::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+}
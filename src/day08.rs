@@ -0,0 +1,1026 @@
+use std::{collections::HashMap, ops::Range, str::FromStr};
+
+use crate::{
+    Solution,
+    disjoint_set::DisjointSet,
+    params::{Param, Params},
+    parse::{ParseError, Source},
+};
+
+pub struct Day08;
+
+/// Coordinates of a junction, in any number of dimensions.
+pub type Junction = Vec<i64>;
+
+/// Two junctions, by index, and their distance. Pairs order by distance, then indices, so that
+/// pairs at equal distance are connected in a deterministic order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pair {
+    pub distance: u128,
+    pub a: usize,
+    pub b: usize,
+}
+
+/// Distance between junctions. Distances are integers so that they compare exactly, which makes
+/// the Euclidean distance squared; [`Metric::length`] converts them back to lengths. They are
+/// computed in `u128`, where only a Euclidean distance over several axes which each differ by
+/// nearly `2^64` can saturate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+}
+
+impl Metric {
+    pub const NAMES: &'static [&'static str] = &["euclidean", "manhattan", "chebyshev"];
+
+    pub fn distance(self, junction_a: &[i64], junction_b: &[i64]) -> u128 {
+        self.combine(
+            junction_a
+                .iter()
+                .zip(junction_b)
+                .map(|(a, b)| a.abs_diff(*b)),
+        )
+    }
+
+    /// Shortest distance from a junction to any point of the box between `low` and `high`.
+    fn distance_to_box(self, junction: &[i64], low: &[i64], high: &[i64]) -> u128 {
+        self.combine(
+            junction
+                .iter()
+                .zip(low.iter().zip(high))
+                .map(|(c, (low, high))| c.abs_diff(*c.clamp(low, high))),
+        )
+    }
+
+    /// Distance of the given differences between coordinates.
+    fn combine(self, differences: impl Iterator<Item = u64>) -> u128 {
+        let differences = differences.map(u128::from);
+        match self {
+            Metric::Euclidean => differences.fold(0, |distance, difference| {
+                distance.saturating_add(difference * difference)
+            }),
+            Metric::Manhattan => differences.sum(),
+            Metric::Chebyshev => differences.max().unwrap_or_default(),
+        }
+    }
+
+    pub fn length(self, distance: u128) -> f64 {
+        match self {
+            Metric::Euclidean => (distance as f64).sqrt(),
+            Metric::Manhattan | Metric::Chebyshev => distance as f64,
+        }
+    }
+
+    /// Distance of a pair of the given length.
+    fn distance_of_length(self, length: u64) -> u128 {
+        match self {
+            Metric::Euclidean => length as u128 * length as u128,
+            Metric::Manhattan | Metric::Chebyshev => length as u128,
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "euclidean" => Ok(Metric::Euclidean),
+            "manhattan" => Ok(Metric::Manhattan),
+            "chebyshev" => Ok(Metric::Chebyshev),
+            _ => Err(format!("Unknown metric `{name}`")),
+        }
+    }
+}
+
+/// Iterator over all pairs of junctions in increasing order, generated lazily so that only the
+/// shortest pairs are computed when only those are needed.
+///
+/// Each round buckets the junctions into cubes of side `cell` and yields the pairs in the same or
+/// adjacent cubes which are longer than the pairs of the previous rounds and not longer than
+/// `cell`. In every metric, a pair differs in each coordinate by at most its length, so any such
+/// pair lies in adjacent cubes. The side then grows by half for the next round, until it spans
+/// the junctions on every axis, and the last rounds yield all the remaining pairs.
+///
+/// A round holds at most `ROUND_PAIRS` pairs. When more pairs qualify, it keeps the shortest ones
+/// and the next round picks up after them with the same side.
+pub struct Pairs<'a> {
+    junctions: &'a [Junction],
+    metric: Metric,
+    cell: u64,
+    /// Largest difference between junctions on any axis.
+    extent: u64,
+    /// Last pair of the previous rounds.
+    reached: Option<Pair>,
+    /// Pairs of the current round, in decreasing order.
+    pending: Vec<Pair>,
+    remaining: usize,
+}
+
+const ROUND_PAIRS: usize = 1 << 20;
+
+/// Pairs of one round, the shortest `ROUND_PAIRS` of those offered after the previous rounds.
+struct Round {
+    after: Option<Pair>,
+    pairs: Vec<Pair>,
+    /// Shortest pair left out of the round, if any.
+    cut: Option<Pair>,
+}
+
+impl Round {
+    fn new(after: Option<Pair>) -> Self {
+        Round {
+            after,
+            pairs: Vec::new(),
+            cut: None,
+        }
+    }
+
+    fn offer(&mut self, pair: Pair) {
+        if self.after.is_some_and(|after| pair <= after) || self.cut.is_some_and(|cut| pair >= cut)
+        {
+            return;
+        }
+        self.pairs.push(pair);
+        if self.pairs.len() == 2 * ROUND_PAIRS {
+            self.pairs.select_nth_unstable(ROUND_PAIRS);
+            self.cut = Some(self.pairs[ROUND_PAIRS]);
+            self.pairs.truncate(ROUND_PAIRS);
+        }
+    }
+}
+
+impl<'a> Pairs<'a> {
+    pub fn new(junctions: &'a [Junction], metric: Metric) -> Self {
+        let dimension = junctions.first().map_or(1, Vec::len);
+        let extent = (0..dimension)
+            .map(|axis| {
+                let min = junctions.iter().map(|j| j[axis]).min().unwrap_or_default();
+                let max = junctions.iter().map(|j| j[axis]).max().unwrap_or_default();
+                max.abs_diff(min)
+            })
+            .max()
+            .unwrap_or_default();
+
+        // Start with cubes as large as the distance from a junction to its nearest neighbour, by
+        // the median over a few junctions, so that far outliers do not make the cubes too large.
+        let samples = junctions.len().min(16);
+        let mut nearest: Vec<u64> = (0..samples)
+            .map(|sample| {
+                let a = sample * junctions.len() / samples;
+                (0..junctions.len())
+                    .filter(|&b| b != a)
+                    .map(|b| Metric::Chebyshev.distance(&junctions[a], &junctions[b]) as u64)
+                    .min()
+                    .unwrap_or_default()
+            })
+            .collect();
+        nearest.sort_unstable();
+        let cell = nearest.get(samples / 2).copied().unwrap_or_default();
+
+        Pairs {
+            junctions,
+            metric,
+            cell: cell.max(1),
+            extent,
+            reached: None,
+            pending: Vec::new(),
+            remaining: junctions.len() * junctions.len().saturating_sub(1) / 2,
+        }
+    }
+
+    fn next_round(&mut self) {
+        let cell = self.cell;
+        let side = match i64::try_from(cell) {
+            Ok(side) if cell < self.extent => side,
+            _ => return self.last_round(),
+        };
+        let limit = self.metric.distance_of_length(cell);
+
+        let mut cubes: HashMap<Vec<i64>, Vec<usize>> = HashMap::new();
+        for (index, junction) in self.junctions.iter().enumerate() {
+            let cube = junction.iter().map(|c| c.div_euclid(side)).collect();
+            cubes.entry(cube).or_default().push(index);
+        }
+
+        // Visiting the adjacent cubes of every cube would cost more than comparing all the pairs.
+        let dimension = self.junctions[0].len() as u32;
+        let adjacent_cubes = match 3usize.checked_pow(dimension) {
+            Some(count) if count <= cubes.len() && count <= self.junctions.len() => count,
+            _ => return self.last_round(),
+        };
+        let mut round = Round::new(self.reached);
+        for (cube, members) in &cubes {
+            for offset in 0..adjacent_cubes {
+                let adjacent: Option<Vec<i64>> = (0..dimension)
+                    .map(|axis| {
+                        cube[axis as usize].checked_add((offset / 3usize.pow(axis) % 3) as i64 - 1)
+                    })
+                    .collect();
+                let Some(others) = adjacent.and_then(|adjacent| cubes.get(&adjacent)) else {
+                    continue;
+                };
+                for &a in members {
+                    for &b in others.iter().filter(|&&b| a < b) {
+                        let distance = self.metric.distance(&self.junctions[a], &self.junctions[b]);
+                        if distance <= limit {
+                            round.offer(Pair { distance, a, b });
+                        }
+                    }
+                }
+            }
+        }
+
+        let cut = self.finish(round);
+        if cut.is_none() {
+            self.reached = Some(Pair {
+                distance: limit,
+                a: usize::MAX,
+                b: usize::MAX,
+            });
+            self.cell = cell.saturating_add(cell / 2 + 1);
+        }
+    }
+
+    /// Yield the pairs longer than the previous rounds whatever their cubes, once the cubes would
+    /// hold all the junctions anyway.
+    fn last_round(&mut self) {
+        let mut round = Round::new(self.reached);
+        for a in 0..self.junctions.len() {
+            for b in a + 1..self.junctions.len() {
+                let distance = self.metric.distance(&self.junctions[a], &self.junctions[b]);
+                round.offer(Pair { distance, a, b });
+            }
+        }
+        self.finish(round);
+    }
+
+    /// Make the pairs of a round pending, and return the pair it left out, if any. The next round
+    /// then starts after the last pending pair.
+    fn finish(&mut self, round: Round) -> Option<Pair> {
+        self.pending = round.pairs;
+        self.pending.sort_unstable_by(|a, b| b.cmp(a));
+        if round.cut.is_some() {
+            self.reached = self.pending.first().copied();
+        }
+        round.cut
+    }
+}
+
+impl Iterator for Pairs<'_> {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            if let Some(pair) = self.pending.pop() {
+                self.remaining -= 1;
+                return Some(pair);
+            }
+            self.next_round();
+        }
+        None
+    }
+}
+
+/// k-d tree over the junctions, to find the nearest junction outside of a circuit.
+struct KdTree<'a> {
+    junctions: &'a [Junction],
+    /// Junction indices, each node holding a contiguous range of them.
+    order: Vec<usize>,
+    /// Nodes, the root first and the children of each node after it.
+    nodes: Vec<Node>,
+}
+
+struct Node {
+    range: Range<usize>,
+    /// Corners of the bounding box of the junctions of the node.
+    low: Vec<i64>,
+    high: Vec<i64>,
+    children: Option<(usize, usize)>,
+}
+
+const LEAF_SIZE: usize = 8;
+
+impl<'a> KdTree<'a> {
+    fn new(junctions: &'a [Junction]) -> Self {
+        let mut tree = KdTree {
+            junctions,
+            order: (0..junctions.len()).collect(),
+            nodes: Vec::new(),
+        };
+        if !junctions.is_empty() {
+            tree.build(0..junctions.len());
+        }
+        tree
+    }
+
+    /// Add the node of the given range of `order`, split at the median of its widest axis.
+    fn build(&mut self, range: Range<usize>) -> usize {
+        let junctions = self.junctions;
+        let members = &mut self.order[range.clone()];
+        let dimension = junctions[members[0]].len();
+        let low: Vec<i64> = (0..dimension)
+            .map(|axis| members.iter().map(|&j| junctions[j][axis]).min().unwrap())
+            .collect();
+        let high: Vec<i64> = (0..dimension)
+            .map(|axis| members.iter().map(|&j| junctions[j][axis]).max().unwrap())
+            .collect();
+
+        let index = self.nodes.len();
+        if members.len() > LEAF_SIZE {
+            let axis = (0..dimension)
+                .max_by_key(|&axis| high[axis].abs_diff(low[axis]))
+                .unwrap();
+            members.select_nth_unstable_by_key(members.len() / 2, |&j| junctions[j][axis]);
+        }
+        let middle = range.start + range.len() / 2;
+        let leaf = range.len() <= LEAF_SIZE;
+        self.nodes.push(Node {
+            range: range.clone(),
+            low,
+            high,
+            children: None,
+        });
+        if !leaf {
+            let left = self.build(range.start..middle);
+            let right = self.build(middle..range.end);
+            self.nodes[index].children = Some((left, right));
+        }
+        index
+    }
+
+    /// The circuit of all the junctions of each node, if they are in a single one.
+    fn node_circuits(&self, circuit: &[usize]) -> Vec<Option<usize>> {
+        let mut node_circuits = vec![None; self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate().rev() {
+            node_circuits[index] = match node.children {
+                Some((left, right)) => {
+                    node_circuits[left].filter(|c| node_circuits[right] == Some(*c))
+                }
+                None => {
+                    let first = circuit[self.order[node.range.start]];
+                    self.order[node.range.clone()]
+                        .iter()
+                        .all(|&j| circuit[j] == first)
+                        .then_some(first)
+                }
+            };
+        }
+        node_circuits
+    }
+
+    /// Shortest pair from junction `a` to a junction of another circuit, or `shortest` if none is
+    /// shorter.
+    fn nearest_outside(
+        &self,
+        a: usize,
+        circuit: &[usize],
+        node_circuits: &[Option<usize>],
+        metric: Metric,
+        mut shortest: Option<Pair>,
+    ) -> Option<Pair> {
+        let junction = &self.junctions[a];
+        let bound = |node: &Node| metric.distance_to_box(junction, &node.low, &node.high);
+        let mut stack = vec![(0, bound(&self.nodes[0]))];
+        while let Some((index, distance)) = stack.pop() {
+            if node_circuits[index] == Some(circuit[a])
+                || shortest.is_some_and(|shortest| distance > shortest.distance)
+            {
+                continue;
+            }
+            let node = &self.nodes[index];
+            match node.children {
+                Some((left, right)) => {
+                    let mut children = [
+                        (left, bound(&self.nodes[left])),
+                        (right, bound(&self.nodes[right])),
+                    ];
+                    // Visit the nearer child first.
+                    if children[0].1 < children[1].1 {
+                        children.swap(0, 1);
+                    }
+                    stack.extend(children);
+                }
+                None => {
+                    for &b in &self.order[node.range.clone()] {
+                        if circuit[b] == circuit[a] {
+                            continue;
+                        }
+                        let pair = Pair {
+                            distance: metric.distance(junction, &self.junctions[b]),
+                            a: a.min(b),
+                            b: a.max(b),
+                        };
+                        if shortest.is_none_or(|shortest| pair < shortest) {
+                            shortest = Some(pair);
+                        }
+                    }
+                }
+            }
+        }
+        shortest
+    }
+}
+
+/// Minimum spanning tree of the junctions, i.e. the connections made until all junctions are in
+/// one circuit.
+///
+/// It is built with Borůvka's algorithm, which connects each circuit to its nearest other circuit
+/// in every step, as found with a k-d tree, so that a far outlier does not require all the pairs up
+/// to its distance as Kruskal's algorithm would. Pairs are totally ordered, so the tree is unique,
+/// and its edges sorted are the connections Kruskal's algorithm would make.
+pub struct SpanningTree<'a> {
+    junctions: &'a [Junction],
+    metric: Metric,
+    /// Edges in the order they were merged.
+    pub edges: Vec<Pair>,
+}
+
+impl<'a> SpanningTree<'a> {
+    pub fn new(junctions: &'a [Junction], metric: Metric) -> Self {
+        let tree = KdTree::new(junctions);
+        let mut circuits = DisjointSet::new(junctions.len());
+        let mut edges = Vec::new();
+        // What is known of the shortest pair from each junction out of its circuit: the pair
+        // itself, or a pair it is not shorter than. Circuits only grow, so both stay true.
+        let mut known: Vec<Option<(Pair, bool)>> = vec![None; junctions.len()];
+        while circuits.components() > 1 {
+            let circuit: Vec<usize> = (0..junctions.len())
+                .map(|junction| circuits.find(junction))
+                .collect();
+            let node_circuits = tree.node_circuits(&circuit);
+
+            // Shortest pair out of each circuit, by the representative of the circuit.
+            let mut shortest: Vec<Option<Pair>> = vec![None; junctions.len()];
+            for a in 0..junctions.len() {
+                let c = circuit[a];
+                let pair = match known[a] {
+                    // The shortest pair from the junction still leaves its circuit.
+                    Some((pair, true)) if circuit[pair.a] != circuit[pair.b] => pair,
+                    // The junction has no pair shorter than the shortest of its circuit so far.
+                    Some((pair, _)) if shortest[c].is_some_and(|shortest| pair >= shortest) => {
+                        continue;
+                    }
+                    // The search only finds pairs shorter than the shortest of the circuit.
+                    _ => {
+                        match tree.nearest_outside(a, &circuit, &node_circuits, metric, shortest[c])
+                        {
+                            Some(pair) if Some(pair) != shortest[c] => {
+                                known[a] = Some((pair, true));
+                                pair
+                            }
+                            found => {
+                                known[a] = found.map(|pair| (pair, false));
+                                continue;
+                            }
+                        }
+                    }
+                };
+                if shortest[c].is_none_or(|shortest| pair < shortest) {
+                    shortest[c] = Some(pair);
+                }
+            }
+            for pair in shortest.into_iter().flatten() {
+                if circuits.union(pair.a, pair.b) {
+                    edges.push(pair);
+                }
+            }
+        }
+        edges.sort_unstable();
+
+        SpanningTree {
+            junctions,
+            metric,
+            edges,
+        }
+    }
+
+    pub fn length(&self, edge: &Pair) -> f64 {
+        self.metric.length(edge.distance)
+    }
+
+    pub fn total_length(&self) -> f64 {
+        self.edges.iter().map(|edge| self.length(edge)).sum()
+    }
+
+    /// The edge joining the last two circuits.
+    pub fn last_edge(&self) -> Option<Pair> {
+        self.edges.last().copied()
+    }
+
+    /// One edge per row, with the indices and coordinates of both junctions and the length.
+    pub fn to_csv(&self) -> String {
+        let axes: Vec<String> = (0..self.junctions.first().map_or(0, Vec::len))
+            .map(|axis| match "xyzw".chars().nth(axis) {
+                Some(name) => name.to_string(),
+                None => axis.to_string(),
+            })
+            .collect();
+        let columns = |junction: &str| {
+            axes.iter()
+                .map(|axis| format!("{junction}_{axis},"))
+                .collect::<String>()
+        };
+
+        let mut csv = format!("a,b,{}{}length\n", columns("a"), columns("b"));
+        for edge in &self.edges {
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                edge.a,
+                edge.b,
+                coordinates(&self.junctions[edge.a]),
+                coordinates(&self.junctions[edge.b]),
+                self.length(edge)
+            );
+        }
+        csv
+    }
+
+    /// Graphviz graph of the tree, labelled with its total length, with the last edge in red.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!(
+            "graph mst {{\n  label=\"total length {:.1}\";\n",
+            self.total_length()
+        );
+        for (index, junction) in self.junctions.iter().enumerate() {
+            dot += &format!("  {index} [label=\"{}\"];\n", coordinates(junction));
+        }
+        for edge in &self.edges {
+            let highlight = match Some(*edge) == self.last_edge() {
+                true => ", color=red, penwidth=2",
+                false => "",
+            };
+            dot += &format!(
+                "  {} -- {} [label=\"{:.1}\"{highlight}];\n",
+                edge.a,
+                edge.b,
+                self.length(edge)
+            );
+        }
+        dot + "}\n"
+    }
+}
+
+fn coordinates(junction: &[i64]) -> String {
+    junction
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// One merge of single-linkage clustering, joining clusters `a` and `b` into a new cluster.
+/// Clusters `0..n` are the single junctions, and the cluster made by the `k`-th merge is `n + k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Merge {
+    pub a: usize,
+    pub b: usize,
+    pub distance: u128,
+    pub size: usize,
+}
+
+/// Single-linkage clustering of the junctions, i.e. the merges of circuits as the connections of
+/// the minimum spanning tree are made, shortest first.
+pub struct Dendrogram {
+    leaves: usize,
+    metric: Metric,
+    pub merges: Vec<Merge>,
+    /// Connection of the spanning tree made by each merge.
+    edges: Vec<Pair>,
+}
+
+impl Dendrogram {
+    pub fn new(junctions: &[Junction], metric: Metric) -> Self {
+        let leaves = junctions.len();
+        let mut circuits = DisjointSet::new(leaves);
+        // Cluster of each circuit, by the representative of the circuit.
+        let mut clusters: Vec<usize> = (0..leaves).collect();
+
+        let mut merges = Vec::new();
+        let edges = SpanningTree::new(junctions, metric).edges;
+        for edge in &edges {
+            let (a, b) = (
+                clusters[circuits.find(edge.a)],
+                clusters[circuits.find(edge.b)],
+            );
+            circuits.union(edge.a, edge.b);
+            let root = circuits.find(edge.a);
+            clusters[root] = leaves + merges.len();
+            merges.push(Merge {
+                a,
+                b,
+                distance: edge.distance,
+                size: circuits.size(root),
+            });
+        }
+
+        Dendrogram {
+            leaves,
+            metric,
+            merges,
+            edges,
+        }
+    }
+
+    /// Length of the connection of a merge.
+    pub fn length(&self, merge: &Merge) -> f64 {
+        self.metric.length(merge.distance)
+    }
+
+    /// Number of clusters when only connections not longer than `length` are made.
+    pub fn clusters_at(&self, length: f64) -> usize {
+        self.leaves - self.merges_within(length)
+    }
+
+    /// Clusters when only connections not longer than `length` are made.
+    pub fn cut_at(&self, length: f64) -> Vec<Vec<usize>> {
+        self.cut_after(self.merges_within(length))
+    }
+
+    /// The `count` clusters made by the shortest connections, or the single junctions if `count`
+    /// is larger than the number of junctions.
+    pub fn cut_into(&self, count: usize) -> Vec<Vec<usize>> {
+        self.cut_after(self.leaves.saturating_sub(count.max(1)))
+    }
+
+    fn merges_within(&self, length: f64) -> usize {
+        self.merges
+            .partition_point(|merge| self.length(merge) <= length)
+    }
+
+    /// Clusters after the first `merges` merges, as junction indices, ordered by their first
+    /// junction.
+    fn cut_after(&self, merges: usize) -> Vec<Vec<usize>> {
+        let mut circuits = DisjointSet::new(self.leaves);
+        for edge in &self.edges[..merges] {
+            circuits.union(edge.a, edge.b);
+        }
+
+        let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+        for junction in 0..self.leaves {
+            clusters
+                .entry(circuits.find(junction))
+                .or_default()
+                .push(junction);
+        }
+        let mut clusters: Vec<Vec<usize>> = clusters.into_values().collect();
+        clusters.sort();
+        clusters
+    }
+
+    /// Height of a cluster, i.e. the length of the connection which made it.
+    fn height(&self, cluster: usize) -> f64 {
+        match cluster.checked_sub(self.leaves) {
+            Some(merge) => self.length(&self.merges[merge]),
+            None => 0.0,
+        }
+    }
+
+    /// One merge per line, with the new cluster, the merged clusters, the length and the size.
+    pub fn to_text(&self) -> String {
+        self.merges
+            .iter()
+            .enumerate()
+            .map(|(index, merge)| {
+                format!(
+                    "{} = {} + {} at {:.3}, size {}\n",
+                    self.leaves + index,
+                    merge.a,
+                    merge.b,
+                    self.length(merge),
+                    merge.size
+                )
+            })
+            .collect()
+    }
+
+    /// Newick tree of the clusters, with the junction indices as leaves and branch lengths as the
+    /// difference of heights.
+    pub fn to_newick(&self) -> String {
+        enum Step {
+            Visit { cluster: usize, parent: f64 },
+            Close { cluster: usize, parent: f64 },
+            Comma,
+        }
+
+        let mut newick = String::new();
+        let Some(root) = (self.leaves + self.merges.len()).checked_sub(1) else {
+            return ";".to_string();
+        };
+        let root_height = self.height(root);
+        let branch = |cluster, parent: f64| match cluster == root {
+            true => String::new(),
+            false => format!(":{:.3}", parent - self.height(cluster)),
+        };
+
+        let mut steps = vec![Step::Visit {
+            cluster: root,
+            parent: root_height,
+        }];
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Visit { cluster, parent } if cluster < self.leaves => {
+                    newick += &format!("{cluster}{}", branch(cluster, parent));
+                }
+                Step::Visit { cluster, parent } => {
+                    let merge = self.merges[cluster - self.leaves];
+                    let height = self.height(cluster);
+                    newick.push('(');
+                    steps.extend([
+                        Step::Close { cluster, parent },
+                        Step::Visit {
+                            cluster: merge.b,
+                            parent: height,
+                        },
+                        Step::Comma,
+                        Step::Visit {
+                            cluster: merge.a,
+                            parent: height,
+                        },
+                    ]);
+                }
+                Step::Close { cluster, parent } => {
+                    newick += &format!("){}", branch(cluster, parent));
+                }
+                Step::Comma => newick.push(','),
+            }
+        }
+
+        newick + ";"
+    }
+}
+
+/// Number of sorted pairs at the same distance as the pair before them, e.g. over all the pairs of
+/// `Pairs`.
+pub fn ties(sorted_pairs: impl IntoIterator<Item = Pair>) -> usize {
+    sorted_pairs
+        .into_iter()
+        .fold((0, None), |(ties, previous), pair| match previous {
+            Some(distance) if distance == pair.distance => (ties + 1, Some(distance)),
+            _ => (ties, Some(pair.distance)),
+        })
+        .0
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const PARAMS: &'static [Param] = &[
+        Param::number(
+            "connections",
+            "1000",
+            "number of shortest connections made in part 1",
+        ),
+        Param::choice(
+            "metric",
+            "euclidean",
+            Metric::NAMES,
+            "distance between junctions",
+        ),
+    ];
+
+    type Parsed = Vec<Junction>;
+    type Answer1 = usize;
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        let mut dimension = None;
+        let junctions: Vec<Junction> = input
+            .lines()
+            .map(|line| {
+                let junction = line
+                    .split(",")
+                    .map(|num| source.number::<i64>(num))
+                    .collect::<Result<Junction, _>>()?;
+                match *dimension.get_or_insert(junction.len()) {
+                    dimension if dimension == junction.len() => Ok(junction),
+                    dimension => Err(source.error(
+                        line,
+                        format!("expected {dimension} coordinates, as the first junction"),
+                    )),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(junctions)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Self::Answer1 {
+        let junctions = input;
+        let mut circuits = DisjointSet::new(junctions.len());
+
+        for Pair { a, b, .. } in
+            Pairs::new(junctions, params.get("metric")).take(params.get::<usize>("connections"))
+        {
+            circuits.union(a, b);
+        }
+
+        let mut sizes = circuits.component_sizes();
+        sizes.sort();
+        sizes.iter().rev().take(3).product()
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Self::Answer2 {
+        let junctions = input;
+        SpanningTree::new(junctions, params.get("metric"))
+            .last_edge()
+            .map_or(0, |Pair { a, b, .. }| {
+                junctions[a][0] as i128 * junctions[b][0] as i128
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day08, Dendrogram, Junction, Metric, Pair, Pairs, SpanningTree, ties};
+    use crate::{Solution, disjoint_set::DisjointSet, params::Params};
+
+    const SHARED_INPUT: &str = r#"
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 40;
+        let params = Params::of::<Day08>().with("connections", 10);
+        assert_eq!(
+            Day08::solve_part1_with(SHARED_INPUT.trim(), &params),
+            Ok(output)
+        );
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 25272;
+        assert_eq!(Day08::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_ties() {
+        // Four corners of a unit square: four sides and two diagonals of equal length.
+        let input = "0,0,0\n1,0,0\n0,1,0\n1,1,0";
+        let junctions = Day08::parse(input).unwrap();
+        let sorted_pair: Vec<Pair> = Pairs::new(&junctions, Metric::Euclidean).collect();
+        assert_eq!(sorted_pair.len(), 6);
+        assert_eq!(ties(sorted_pair), 4);
+
+        let params = Params::of::<Day08>().with("connections", 3);
+        assert_eq!(Day08::solve_part1_with(input, &params), Ok(4));
+    }
+
+    #[test]
+    fn test_pairs_in_order() {
+        let junctions = Day08::parse(SHARED_INPUT.trim()).unwrap();
+        for metric in [Metric::Euclidean, Metric::Manhattan, Metric::Chebyshev] {
+            let mut expected = Vec::new();
+            for a in 0..junctions.len() {
+                for b in a + 1..junctions.len() {
+                    let distance = metric.distance(&junctions[a], &junctions[b]);
+                    expected.push(Pair { distance, a, b });
+                }
+            }
+            expected.sort();
+            assert_eq!(Pairs::new(&junctions, metric).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree() {
+        let junctions = Day08::parse(SHARED_INPUT.trim()).unwrap();
+        let tree = SpanningTree::new(&junctions, Metric::Euclidean);
+        assert_eq!(tree.edges.len(), junctions.len() - 1);
+        assert_eq!(
+            tree.last_edge().map(|edge| (edge.a, edge.b)),
+            Some((10, 12))
+        );
+        assert_eq!(tree.to_csv().lines().count(), junctions.len());
+        assert!(
+            tree.to_dot()
+                .contains("10 -- 12 [label=\"458.4\", color=red")
+        );
+    }
+
+    #[test]
+    fn test_metrics() {
+        let input = "-3,0\n1,4\n5,-1\n-3,-2";
+        let params = Params::of::<Day08>().with("metric", "manhattan");
+        assert_eq!(Day08::solve_part2_with(input, &params), Ok(-15));
+        let params = Params::of::<Day08>().with("metric", "chebyshev");
+        assert_eq!(Day08::solve_part2_with(input, &params), Ok(5));
+
+        assert!(Day08::parse("1,2,3\n4,5").is_err());
+    }
+
+    #[test]
+    fn test_dendrogram() {
+        let junctions = Day08::parse("0,0,0\n3,4,0\n3,4,12\n20,0,0").unwrap();
+        let dendrogram = Dendrogram::new(&junctions, Metric::Euclidean);
+        assert_eq!(dendrogram.merges.len(), 3);
+        assert_eq!(dendrogram.clusters_at(4.9), 4);
+        assert_eq!(dendrogram.clusters_at(12.0), 2);
+        assert_eq!(dendrogram.cut_at(12.0), [vec![0, 1, 2], vec![3]]);
+        assert_eq!(dendrogram.cut_into(3), [vec![0, 1], vec![2], vec![3]]);
+        assert_eq!(
+            dendrogram.to_newick(),
+            "(((0:5.000,1:5.000):7.000,2:12.000):5.464,3:17.464);"
+        );
+    }
+
+    #[test]
+    fn test_wide_coordinates() {
+        let input = "-5000000000,0\n5000000000,0\n0,1";
+        assert_eq!(Day08::solve_part2(input), Ok(0));
+        let input = "-5000000000,0\n5000000000,0";
+        assert_eq!(Day08::solve_part2(input), Ok(-25000000000000000000));
+
+        let input = "-9223372036854775808\n9223372036854775807";
+        let params = Params::of::<Day08>().with("metric", "manhattan");
+        assert_eq!(
+            Day08::solve_part2_with(input, &params),
+            Ok(i64::MIN as i128 * i64::MAX as i128)
+        );
+        let junctions = Day08::parse(input).unwrap();
+        let pairs: Vec<Pair> = Pairs::new(&junctions, Metric::Euclidean).collect();
+        assert_eq!(pairs[0].distance, (u64::MAX as u128).pow(2));
+    }
+
+    #[test]
+    fn test_high_dimension() {
+        // Junctions along the diagonal of 45 dimensions, each step longer than the one before.
+        let input: Vec<String> = (0..5i64)
+            .map(|i| vec![(i * i).to_string(); 45].join(","))
+            .collect();
+        let junctions = Day08::parse(&input.join("\n")).unwrap();
+        let pairs: Vec<Pair> = Pairs::new(&junctions, Metric::Chebyshev).collect();
+        assert_eq!(pairs.len(), 10);
+        assert_eq!((pairs[0].a, pairs[0].b, pairs[0].distance), (0, 1, 1));
+        assert!(pairs.windows(2).all(|pairs| pairs[0] < pairs[1]));
+
+        let junctions = random_junctions(300, 8, 1000);
+        let tree = SpanningTree::new(&junctions, Metric::Euclidean);
+        assert_eq!(tree.edges.len(), 299);
+    }
+
+    #[test]
+    fn test_outlier() {
+        // A cluster with many ties, and one junction far away from it.
+        let mut junctions = random_junctions(400, 3, 20);
+        junctions.push(vec![1_000_000_000; 3]);
+        for metric in [Metric::Euclidean, Metric::Manhattan, Metric::Chebyshev] {
+            let mut expected = Vec::new();
+            for a in 0..junctions.len() {
+                for b in a + 1..junctions.len() {
+                    let distance = metric.distance(&junctions[a], &junctions[b]);
+                    expected.push(Pair { distance, a, b });
+                }
+            }
+            expected.sort();
+            let pairs: Vec<Pair> = Pairs::new(&junctions, metric).take(1000).collect();
+            assert_eq!(pairs, expected[..1000]);
+
+            // Kruskal's algorithm over all the pairs.
+            let mut circuits = DisjointSet::new(junctions.len());
+            let edges: Vec<Pair> = expected
+                .into_iter()
+                .filter(|pair| circuits.union(pair.a, pair.b))
+                .collect();
+            assert_eq!(SpanningTree::new(&junctions, metric).edges, edges);
+        }
+    }
+
+    /// Junctions with coordinates from 0 to `range`, from a fixed seed.
+    fn random_junctions(count: usize, dimension: usize, range: i64) -> Vec<Junction> {
+        let mut seed = 1u64;
+        let mut random = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as i64 % range
+        };
+        (0..count)
+            .map(|_| (0..dimension).map(|_| random()).collect())
+            .collect()
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    Solution,
+    params::Params,
+    parse::{ParseError, Source},
+};
+
+pub struct Day09;
+
+pub type Tile = (u64, u64);
+type Line = (Tile, Tile);
+
+fn area(tile_a: Tile, tile_b: Tile) -> u64 {
+    (tile_a.0.abs_diff(tile_b.0) + 1) * (tile_a.1.abs_diff(tile_b.1) + 1)
+}
+
+fn line_cut_box(line: Line, tile_a: Tile, tile_b: Tile) -> bool {
+    let head = line.0;
+    let tail = line.1;
+
+    if head.0 == tail.0 {
+        // Vertical line
+        let horizontal_condition =
+            tile_a.0.min(tile_b.0) < head.0 && head.0 < tile_a.0.max(tile_b.0);
+        let vertical_conditionb = !((head.1.max(tail.1) <= tile_a.1.min(tile_b.1))
+            || (head.1.min(tail.1) >= tile_a.1.max(tile_b.1)));
+        horizontal_condition && vertical_conditionb
+    } else if head.1 == tail.1 {
+        // Horizontal line
+        let horizontal_condition = !((head.0.max(tail.0) <= tile_a.0.min(tile_b.0))
+            || (head.0.min(tail.0) >= tile_a.0.max(tile_b.0)));
+        let vertical_conditionb =
+            tile_a.1.min(tile_b.1) < head.1 && head.1 < tile_a.1.max(tile_b.1);
+        horizontal_condition && vertical_conditionb
+    } else {
+        unreachable!("Consecutive tiles are checked to share an axis when parsing")
+    }
+}
+
+fn lines_cut_box(lines: &[Line], tile_a: Tile, tile_b: Tile) -> bool {
+    lines.iter().any(|line| line_cut_box(*line, tile_a, tile_b))
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Tile>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        let tiles: Vec<(&str, Tile)> = input
+            .lines()
+            .map(|line| {
+                let (x, y) = source.split_once(line, ",")?;
+                Ok((line, (source.number::<u64>(x)?, source.number::<u64>(y)?)))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        if tiles.is_empty() {
+            return Err(source.error(input, "expected at least one tile"));
+        }
+
+        // Each tile is joined to the next one, and the last one to the first, by a straight line.
+        for (index, &(line, tile)) in tiles.iter().enumerate() {
+            let (_, next) = tiles[(index + 1) % tiles.len()];
+            if tile.0 != next.0 && tile.1 != next.1 {
+                return Err(source.error(
+                    line,
+                    format!(
+                        "tile shares no row or column with the next tile {},{}",
+                        next.0, next.1
+                    ),
+                ));
+            }
+        }
+
+        Ok(tiles.into_iter().map(|(_, tile)| tile).collect())
+    }
+
+    fn part1(input: &Self::Parsed, _params: &Params) -> Self::Answer1 {
+        let tiles = input;
+
+        let mut largest_area = 0;
+        for i in 0..tiles.len() {
+            for j in i + 1..tiles.len() {
+                let area = area(tiles[i], tiles[j]);
+                if area > largest_area {
+                    largest_area = area;
+                }
+            }
+        }
+
+        largest_area
+    }
+
+    fn part2(input: &Self::Parsed, _params: &Params) -> Self::Answer2 {
+        let tiles = input;
+
+        let lines: Vec<Line> = [tiles.clone(), vec![*tiles.first().unwrap()]]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<Tile>>()
+            .windows(2)
+            .map(|window| (window[0], window[1]))
+            .collect();
+
+        let mut largest_area = 0;
+        for i in 0..tiles.len() {
+            for j in i + 1..tiles.len() {
+                let tile_a = tiles[i];
+                let tile_b = tiles[j];
+
+                if lines_cut_box(&lines, tile_a, tile_b) {
+                    continue;
+                }
+
+                let area = area(tile_a, tile_b);
+                if area > largest_area {
+                    largest_area = area;
+                }
+            }
+        }
+
+        largest_area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day09;
+    use crate::Solution;
+
+    const SHARED_INPUT: &str = r#"
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 50;
+        assert_eq!(Day09::solve_part1(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 24;
+        assert_eq!(Day09::solve_part2(SHARED_INPUT.trim()), Ok(output));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day09::parse("1,1\n5,5\n1,5").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        // The last tile joins the first one.
+        let error = Day09::parse("1,1\n1,5\n5,5").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+
+        assert!(Day09::parse("").is_err());
+    }
+}
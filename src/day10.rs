@@ -0,0 +1,565 @@
+use std::fmt;
+#[cfg(feature = "cbc")]
+use std::iter::Sum;
+
+#[cfg(feature = "cbc")]
+use good_lp::{
+    Expression, ResolutionError, Solution as _, SolverModel, constraint, default_solver, variables,
+};
+
+use crate::{
+    Answer, Part, Solution,
+    gf2::Solutions,
+    ilp::{self, SolveError},
+    model::{Constraint, Model},
+    parallel,
+    params::{Param, Params},
+    parse::{ParseError, Source, chars},
+};
+
+pub struct Day10;
+
+// Part 2 is solved by the integer programming solver in the crate, or with the `cbc` feature, by
+// COIN-OR Branch-and-Cut solver (https://github.com/coin-or/Cbc), selected by the `solver`
+// parameter. To install CBC, you can run the following commands:
+//
+// Arch Linux:
+// $ pacman -S coin-or-cbc
+//
+// Debian:
+// $ sudo apt-get install  coinor-cbc coinor-libcbc-dev
+
+pub type Light = u8;
+pub type Button = Vec<usize>;
+pub type Joltage = u32;
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    /// Line of the machine in the input, from 1.
+    pub line: usize,
+    pub lights: Vec<Light>,
+    pub buttons: Vec<Button>,
+    pub joltages: Vec<Joltage>,
+}
+
+impl Machine {
+    /// Lights after pressing each button the given number of times, starting with all lights off.
+    pub fn lights_after(&self, presses: &[u64]) -> Vec<Light> {
+        let mut lights = vec![0; self.lights.len()];
+        for (button, count) in self.buttons.iter().zip(presses) {
+            for &light in button {
+                lights[light] ^= (count % 2) as Light;
+            }
+        }
+        lights
+    }
+
+    /// Joltages after pressing each button the given number of times, starting from zero.
+    pub fn joltages_after(&self, presses: &[u64]) -> Vec<Joltage> {
+        let mut joltages = vec![0; self.joltages.len()];
+        for (button, count) in self.buttons.iter().zip(presses) {
+            for &joltage in button {
+                joltages[joltage] += *count as Joltage;
+            }
+        }
+        joltages
+    }
+}
+
+/// Presses of each button of one machine in a solution with the fewest presses, or why it could not
+/// be solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub line: usize,
+    pub presses: Result<Vec<u64>, SolveError>,
+}
+
+/// Outcomes of all machines. Displays as the total presses of the machines which could be solved,
+/// followed by a summary of the failures, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    pub fn total(&self) -> u64 {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| outcome.presses.as_ref().ok())
+            .flatten()
+            .sum()
+    }
+
+    /// Line and error of each machine which could not be solved.
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &SolveError)> {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| Some((outcome.line, outcome.presses.as_ref().err()?)))
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.total())
+    }
+}
+
+/// The total is only the answer when every machine is solved. Otherwise the error gives the total
+/// over the solved machines along with the failures.
+impl Answer for Report {
+    fn answer(&self) -> Result<String, String> {
+        let failures: Vec<String> = self
+            .failures()
+            .map(|(line, error)| format!("line {line}: {error}"))
+            .collect();
+        match failures.is_empty() {
+            true => Ok(self.total().to_string()),
+            false => Err(format!(
+                "sum over {} solved machines is {}; {} of {} machines failed: {}",
+                self.outcomes.len() - failures.len(),
+                self.total(),
+                failures.len(),
+                self.outcomes.len(),
+                failures.join(", ")
+            )),
+        }
+    }
+}
+
+/// For each machine, the presses of each button and whether they reproduce the target of the part,
+/// one machine per line, followed by the number of machines whose presses do not.
+pub fn explain(machines: &[Machine], report: &Report, part: Part) -> String {
+    let mut explanation = String::new();
+    let mut mismatches = 0;
+    for (machine, outcome) in machines.iter().zip(&report.outcomes) {
+        let presses = match &outcome.presses {
+            Ok(presses) => presses,
+            Err(error) => {
+                explanation += &format!("line {}: {error}\n", outcome.line);
+                continue;
+            }
+        };
+
+        let buttons: Vec<String> = machine
+            .buttons
+            .iter()
+            .zip(presses)
+            .filter(|(_, count)| **count > 0)
+            .map(|(button, count)| {
+                let lights: Vec<String> = button.iter().map(usize::to_string).collect();
+                format!("({})x{count}", lights.join(","))
+            })
+            .collect();
+        let (reached, target) = match part {
+            Part::Part1 => {
+                let pattern = |lights: &[Light]| -> String {
+                    lights
+                        .iter()
+                        .map(|light| ['.', '#'][*light as usize])
+                        .collect()
+                };
+                (
+                    format!("[{}]", pattern(&machine.lights_after(presses))),
+                    format!("[{}]", pattern(&machine.lights)),
+                )
+            }
+            Part::Part2 => {
+                let counters = |joltages: &[Joltage]| -> String {
+                    let joltages: Vec<String> = joltages.iter().map(u32::to_string).collect();
+                    joltages.join(",")
+                };
+                (
+                    format!("{{{}}}", counters(&machine.joltages_after(presses))),
+                    format!("{{{}}}", counters(&machine.joltages)),
+                )
+            }
+        };
+        let check = match reached == target {
+            true => "ok".to_string(),
+            false => {
+                mismatches += 1;
+                format!("MISMATCH (expected {target})")
+            }
+        };
+
+        explanation += &format!(
+            "line {}: {} = {} presses, {reached} {check}\n",
+            outcome.line,
+            buttons.join(" "),
+            presses.iter().sum::<u64>()
+        );
+    }
+
+    explanation + &format!("{mismatches} mismatches\n")
+}
+
+/// Integer program of a machine for the part, minimising the presses `x` of the buttons. In part 1,
+/// the presses toggling each light must be `2 m + 1` for a lit light or `2 m` otherwise, for some
+/// multiplier `m`; in part 2, they must add up to the joltage.
+pub fn model(machine: &Machine, part: Part) -> Model {
+    let buttons = machine.buttons.len();
+    let mut variables: Vec<String> = (0..buttons).map(|button| format!("x{button}")).collect();
+    let counters = match part {
+        Part::Part1 => machine.lights.len(),
+        Part::Part2 => machine.joltages.len(),
+    };
+
+    let constraints = (0..counters)
+        .map(|counter| {
+            let mut terms: Vec<(usize, i64)> = machine
+                .buttons
+                .iter()
+                .enumerate()
+                .filter(|(_, button)| button.contains(&counter))
+                .map(|(column, _)| (column, 1))
+                .collect();
+            let rhs = match part {
+                Part::Part1 => {
+                    terms.push((variables.len(), -2));
+                    variables.push(format!("m{counter}"));
+                    machine.lights[counter] as i64
+                }
+                Part::Part2 => machine.joltages[counter] as i64,
+            };
+            Constraint {
+                name: format!("c{counter}"),
+                terms,
+                rhs,
+            }
+        })
+        .collect();
+
+    Model {
+        name: format!("day10_line{}_part{}", machine.line, part.number()),
+        variables,
+        objective: (0..buttons).map(|button| (button, 1)).collect(),
+        constraints,
+    }
+}
+
+/// Largest null space whose `2^k` solutions are searched for the fewest presses.
+const MAX_NULL_SPACE: usize = 24;
+
+/// Fewest presses to toggle the lights into the pattern.
+///
+/// Pressing a button twice cancels out, so each button is pressed at most once, and the lights
+/// toggled by the pressed buttons must add up to the pattern over GF(2).
+fn light_presses(machine: &Machine) -> Result<Vec<u64>, SolveError> {
+    if machine.buttons.len() > 64 || machine.lights.len() > 64 {
+        return Err(SolveError::Failed(
+            "more than 64 buttons or lights".to_string(),
+        ));
+    }
+
+    let columns: Vec<u64> = machine
+        .buttons
+        .iter()
+        .map(|button| button.iter().fold(0, |mask, light| mask | 1 << light))
+        .collect();
+    let pattern = machine
+        .lights
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (index, light)| mask | (*light as u64) << index);
+
+    let solutions = Solutions::new(&columns, pattern).ok_or(SolveError::Infeasible)?;
+    if solutions.null_space.len() > MAX_NULL_SPACE {
+        return Err(SolveError::Failed("null space too large".to_string()));
+    }
+    let pressed = solutions.min_weight();
+    Ok((0..columns.len())
+        .map(|button| pressed >> button & 1)
+        .collect())
+}
+
+#[cfg(feature = "cbc")]
+const SOLVERS: &[&str] = &["cbc", "native"];
+#[cfg(not(feature = "cbc"))]
+const SOLVERS: &[&str] = &["native"];
+
+/// Fewest presses to reach the joltages, with the solver in the crate.
+fn joltage_presses_native(machine: &Machine) -> Result<Vec<u64>, SolveError> {
+    let matrix: Vec<Vec<i64>> = (0..machine.joltages.len())
+        .map(|joltage_index| {
+            machine
+                .buttons
+                .iter()
+                .map(|button| button.contains(&joltage_index) as i64)
+                .collect()
+        })
+        .collect();
+    let rhs: Vec<i64> = machine
+        .joltages
+        .iter()
+        .map(|&joltage| joltage as i64)
+        .collect();
+
+    ilp::minimise_sum(&matrix, &rhs)
+}
+
+/// Fewest presses to reach the joltages, with CBC.
+#[cfg(feature = "cbc")]
+fn joltage_presses_cbc(machine: &Machine) -> Result<Vec<u64>, SolveError> {
+    variables! {
+        vars:
+            0 <= x[machine.buttons.len()] (integer);
+    }
+
+    let mut lp = vars
+        .minimise(Expression::sum(x.iter()))
+        .using(default_solver);
+    for (joltage_index, &joltage) in machine.joltages.iter().enumerate() {
+        let lin_comb = Expression::sum(
+            machine
+                .buttons
+                .iter()
+                .enumerate()
+                .filter(|(_, button)| button.contains(&joltage_index))
+                .map(|(column, _)| x[column]),
+        );
+        lp = lp.with(constraint!(lin_comb == joltage))
+    }
+
+    let solution = lp.solve().map_err(|error| match error {
+        ResolutionError::Infeasible => SolveError::Infeasible,
+        ResolutionError::Unbounded => SolveError::Unbounded,
+        error => SolveError::Failed(error.to_string()),
+    })?;
+    Ok(x.iter()
+        .map(|&variable| solution.value(variable).round() as u64)
+        .collect())
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const PARAMS: &'static [Param] = &[
+        Param::choice(
+            "solver",
+            SOLVERS[0],
+            SOLVERS,
+            "integer programming solver of part 2",
+        ),
+        Param::number(
+            "workers",
+            "1",
+            "number of threads solving machines, or 0 for one per CPU; must be 1 with CBC",
+        ),
+    ];
+
+    type Parsed = Vec<Machine>;
+    type Answer1 = Report;
+    type Answer2 = Result<Report, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let section: Vec<&str> = line.split_ascii_whitespace().collect();
+                let [first, .., last] = section[..] else {
+                    return Err(source.error(line, "expected lights, buttons and joltages"));
+                };
+                let lights: Vec<Light> = chars(source.enclosed(first, '[', ']')?)
+                    .map(|(light, span)| match light {
+                        '.' => Ok(0),
+                        '#' => Ok(1),
+                        _ => Err(source.error(span, "unknown light, expected `.` or `#`")),
+                    })
+                    .collect::<Result<_, _>>()?;
+                let buttons: Vec<Button> = section
+                    .iter()
+                    .take(section.len() - 1)
+                    .skip(1)
+                    .map(|button_str| {
+                        source
+                            .enclosed(button_str, '(', ')')?
+                            .split(",")
+                            .map(|num| match source.number(num)? {
+                                index if index < lights.len() => Ok(index),
+                                index => Err(source.error(
+                                    num,
+                                    format!(
+                                        "button index {index} out of range, expected less than {}",
+                                        lights.len()
+                                    ),
+                                )),
+                            })
+                            .collect::<Result<Button, _>>()
+                    })
+                    .collect::<Result<_, _>>()?;
+                let joltages: Vec<Joltage> = source
+                    .enclosed(last, '{', '}')?
+                    .split(",")
+                    .map(|num| source.number(num))
+                    .collect::<Result<_, _>>()?;
+                if joltages.len() != lights.len() {
+                    return Err(source.error(
+                        last,
+                        format!(
+                            "expected {} joltages, one per light, found {}",
+                            lights.len(),
+                            joltages.len()
+                        ),
+                    ));
+                }
+
+                Ok(Machine {
+                    line: index + 1,
+                    lights,
+                    buttons,
+                    joltages,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Self::Answer1 {
+        let machines = input;
+
+        Report {
+            outcomes: parallel::map(machines, params.get("workers"), |machine| Outcome {
+                line: machine.line,
+                presses: light_presses(machine),
+            }),
+        }
+    }
+
+    /// libcbc is not thread-safe, so `coin_cbc` runs one solve at a time behind a global lock, and
+    /// CBC is rejected with more than one worker rather than leaving the extra threads waiting.
+    fn part2(input: &Self::Parsed, params: &Params) -> Self::Answer2 {
+        let machines = input;
+        let solver = params.get::<String>("solver");
+        let workers = params.get("workers");
+        if solver == "cbc" && workers != 1 {
+            return Err(format!(
+                "CBC solves one machine at a time, use workers=1 or solver=native instead of workers={workers}"
+            ));
+        }
+
+        Ok(Report {
+            outcomes: parallel::map(machines, workers, |machine| Outcome {
+                line: machine.line,
+                presses: match solver.as_str() {
+                    #[cfg(feature = "cbc")]
+                    "cbc" => joltage_presses_cbc(machine),
+                    _ => joltage_presses_native(machine),
+                },
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day10, explain, model};
+    use crate::{Answer, Part, Solution, ilp::SolveError, params::Params};
+
+    const SHARED_INPUT: &str = r#"
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 7;
+        assert_eq!(
+            Day10::solve_part1(SHARED_INPUT.trim()).map(|report| report.total()),
+            Ok(output)
+        );
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 33;
+        assert_eq!(
+            Day10::solve_part2(SHARED_INPUT.trim())
+                .map(|report| report.map(|report| report.total())),
+            Ok(Ok(output))
+        );
+    }
+
+    #[test]
+    fn test_part2_native() {
+        let output = 33;
+        let params = Params::of::<Day10>().with("solver", "native");
+        assert_eq!(
+            Day10::solve_part2_with(SHARED_INPUT.trim(), &params)
+                .map(|report| report.map(|report| report.total())),
+            Ok(Ok(output))
+        );
+
+        let params = params.with("workers", 2);
+        assert_eq!(
+            Day10::solve_part2_with(SHARED_INPUT.trim(), &params)
+                .map(|report| report.map(|report| report.total())),
+            Ok(Ok(output))
+        );
+
+        #[cfg(feature = "cbc")]
+        assert_eq!(
+            Day10::solve_part2_with(SHARED_INPUT.trim(), &params.with("solver", "cbc"))
+                .map(|report| report.map(|report| report.total())),
+            Ok(Err(
+                "CBC solves one machine at a time, use workers=1 or solver=native instead of workers=2"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        // The second machine can only toggle both lights together, or reach equal joltages.
+        let input = "[##] (0,1) {2,2}\n[#.] (0,1) {1,2}";
+        let params = Params::of::<Day10>().with("solver", "native");
+
+        let report = Day10::solve_part1_with(input, &params).unwrap();
+        assert_eq!(report.total(), 1);
+        assert_eq!(
+            report.answer(),
+            Err(
+                "sum over 1 solved machines is 1; 1 of 2 machines failed: line 2: infeasible"
+                    .to_string()
+            )
+        );
+
+        let report = Day10::solve_part2_with(input, &params).unwrap().unwrap();
+        assert_eq!(report.total(), 2);
+        let machines = Day10::parse(input).unwrap();
+        assert_eq!(
+            explain(&machines, &report, Part::Part2),
+            "line 1: (0,1)x2 = 2 presses, {2,2} ok\nline 2: infeasible\n0 mismatches\n"
+        );
+        assert_eq!(
+            report.failures().collect::<Vec<_>>(),
+            [(2, &SolveError::Infeasible)]
+        );
+
+        // Pressing any odd number of the 30 identical buttons gives 2^29 solutions to search.
+        let input = format!("[#] {}{{1}}", "(0) ".repeat(30));
+        let report = Day10::solve_part1_with(&input, &params).unwrap();
+        assert_eq!(
+            report.failures().collect::<Vec<_>>(),
+            [(1, &SolveError::Failed("null space too large".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_model() {
+        let machines = Day10::parse(SHARED_INPUT.trim()).unwrap();
+        let lp = model(&machines[0], Part::Part1).to_lp();
+        assert!(lp.contains(" c3: x0 + x1 + x3 - 2 m3 = 0\n"));
+        let lp = model(&machines[0], Part::Part2).to_lp();
+        assert!(lp.contains(" c3: x0 + x1 + x3 = 7\n"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day10::parse("[.#] (0,1) {1,1}\n[.#] (0,2) {1,1}").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        let error = Day10::parse("[.#] (0,1) {1,1,1}").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use crate::{
+    Solution,
+    params::{Param, Params},
+    parse::{ParseError, Source},
+};
+
+pub struct Day11;
+
+pub type Device = String;
+/// Outputs of each device.
+pub type Map = HashMap<Device, Vec<Device>>;
+
+/// Cycle of devices reachable between the start and the end, through which there are infinitely
+/// many paths.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError {
+    /// Devices of the cycle, in the direction of the connections.
+    pub cycle: Vec<Device>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cycle between the start and the end: {} -> {}",
+            self.cycle.join(" -> "),
+            self.cycle[0]
+        )
+    }
+}
+
+fn outputs<'a>(map: &'a Map, device: &str) -> impl Iterator<Item = &'a str> {
+    map.get(device).into_iter().flatten().map(String::as_str)
+}
+
+/// Devices on some path from `start` to `end`, in topological order, or a cycle among them.
+fn topological_order<'a>(
+    map: &'a Map,
+    start: &'a str,
+    end: &'a str,
+) -> Result<Vec<&'a str>, CycleError> {
+    // Devices reachable from the start, and the inputs of each of them.
+    let mut reachable = HashSet::from([start]);
+    let mut inputs: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut stack = vec![start];
+    while let Some(device) = stack.pop() {
+        for output in outputs(map, device) {
+            inputs.entry(output).or_default().push(device);
+            if reachable.insert(output) {
+                stack.push(output);
+            }
+        }
+    }
+    if !reachable.contains(end) {
+        return Ok(Vec::new());
+    }
+
+    // Of those, the devices from which the end is reachable.
+    let mut relevant = HashSet::from([end]);
+    let mut stack = vec![end];
+    while let Some(device) = stack.pop() {
+        for &input in inputs.get(device).into_iter().flatten() {
+            if relevant.insert(input) {
+                stack.push(input);
+            }
+        }
+    }
+    let relevant_inputs = |device: &str| {
+        inputs
+            .get(device)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|input| relevant.contains(input))
+    };
+
+    // Kahn's algorithm: a device comes after all of its inputs. The start comes first whatever its
+    // inputs, and is never queued again.
+    let mut in_degree: HashMap<&str, usize> = relevant
+        .iter()
+        .map(|&device| (device, relevant_inputs(device).count()))
+        .collect();
+    let mut queue: VecDeque<&str> = VecDeque::from([start]);
+    let mut order = Vec::new();
+    while let Some(device) = queue.pop_front() {
+        order.push(device);
+        for output in outputs(map, device).filter(|output| relevant.contains(output)) {
+            let degree = in_degree.get_mut(output).unwrap();
+            *degree -= 1;
+            if *degree == 0 && output != start {
+                queue.push_back(output);
+            }
+        }
+    }
+
+    // Every device left out of the order has an input left out too, so following such inputs from
+    // any of them runs into a cycle. If none is left out but the start has an input, following any
+    // inputs from the start leads back to it, as every other device has one on the way from the
+    // start.
+    let processed: HashSet<&str> = order.iter().copied().collect();
+    let mut device = match relevant
+        .iter()
+        .copied()
+        .filter(|device| !processed.contains(device))
+        .min()
+    {
+        Some(device) => device,
+        None if relevant_inputs(start).next().is_some() => start,
+        None => return Ok(order),
+    };
+    let from_start = device == start;
+    let mut walk: Vec<&str> = Vec::new();
+    while !walk.contains(&device) {
+        walk.push(device);
+        device = relevant_inputs(device)
+            .filter(|input| from_start || !processed.contains(input))
+            .min()
+            .unwrap();
+    }
+    let position = walk.iter().position(|walked| *walked == device).unwrap();
+    let mut cycle: Vec<Device> = walk[position..].iter().map(|d| d.to_string()).collect();
+    cycle.reverse();
+    let first = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap();
+    cycle.rotate_left(first);
+    Err(CycleError { cycle })
+}
+
+/// Number of paths from `start` to `end`, counted over the devices in topological order.
+pub fn count_paths(map: &Map, start: &str, end: &str) -> Result<usize, CycleError> {
+    if start == end {
+        return Ok(1);
+    }
+
+    let order = topological_order(map, start, end)?;
+    let mut counts: HashMap<&str, usize> = HashMap::from([(start, 1)]);
+    for &device in &order {
+        let count = counts.get(device).copied().unwrap_or_default();
+        for output in outputs(map, device) {
+            *counts.entry(output).or_default() += count;
+        }
+    }
+
+    Ok(counts.get(end).copied().unwrap_or_default())
+}
+
+/// Number of paths from `start` to `end` which visit all of `waypoints`, in any order. Each
+/// device on the way is counted with every subset of the waypoints visited before reaching it.
+pub fn count_paths_through(
+    map: &Map,
+    start: &str,
+    end: &str,
+    waypoints: &[&str],
+) -> Result<usize, CycleError> {
+    assert!(waypoints.len() < 32, "Too many waypoints");
+    let visited = |device: &str| {
+        waypoints
+            .iter()
+            .enumerate()
+            .filter(|(_, waypoint)| **waypoint == device)
+            .fold(0, |mask, (index, _)| mask | 1 << index)
+    };
+    let all = (1 << waypoints.len()) - 1;
+    if start == end {
+        return Ok((visited(start) == all).into());
+    }
+
+    let order = topological_order(map, start, end)?;
+    let mut counts: HashMap<&str, Vec<usize>> = HashMap::new();
+    counts.entry(start).or_insert(vec![0; all + 1])[visited(start)] = 1;
+    for &device in &order {
+        let Some(by_mask) = counts.get(device).cloned() else {
+            continue;
+        };
+        for output in outputs(map, device) {
+            let output_counts = counts.entry(output).or_insert(vec![0; all + 1]);
+            for (mask, count) in by_mask.iter().enumerate() {
+                output_counts[mask | visited(output)] += count;
+            }
+        }
+    }
+
+    Ok(counts.get(end).map_or(0, |by_mask| by_mask[all]))
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMS: &'static [Param] = &[
+        Param::text("start", "you", "device where the paths of part 1 start"),
+        Param::text("server", "svr", "device where the paths of part 2 start"),
+        Param::text(
+            "waypoints",
+            "fft,dac",
+            "comma-separated devices visited in any order by the paths of part 2",
+        ),
+        Param::text("end", "out", "device where the paths end"),
+    ];
+
+    type Parsed = Map;
+    type Answer1 = Result<usize, CycleError>;
+    type Answer2 = Result<usize, CycleError>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        let mut pairs = Vec::new();
+        for line in input.lines() {
+            let (device, output_list) = source.split_once(line, ": ")?;
+            pairs.extend(
+                output_list
+                    .split_whitespace()
+                    .map(|output| (device.to_string(), output.to_string())),
+            );
+        }
+
+        let mut map = Map::new();
+        for (input, output) in pairs {
+            map.entry(input).or_default().push(output);
+        }
+
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Self::Answer1 {
+        let map = input;
+        let start = params.get::<String>("start");
+        let end = params.get::<String>("end");
+
+        count_paths(map, &start, &end)
+    }
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Self::Answer2 {
+        let map = input;
+        let server = params.get::<String>("server");
+        let waypoints = params.get::<String>("waypoints");
+        let end = params.get::<String>("end");
+
+        let waypoints: Vec<&str> = waypoints
+            .split(",")
+            .filter(|waypoint| !waypoint.is_empty())
+            .collect();
+
+        count_paths_through(map, &server, &end, &waypoints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CycleError, Day11, count_paths_through};
+    use crate::{Error, Part, Solution, params::Params, puzzle};
+
+    const SHARED_INPUT_1: &str = r#"
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+"#;
+
+    const SHARED_INPUT_2: &str = r#"
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+"#;
+
+    #[test]
+    fn test_part1_example() {
+        let output = 5;
+        assert_eq!(Day11::solve_part1(SHARED_INPUT_1.trim()), Ok(Ok(output)));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let output = 2;
+        assert_eq!(Day11::solve_part2(SHARED_INPUT_2.trim()), Ok(Ok(output)));
+    }
+
+    #[test]
+    fn test_cycle() {
+        // The cycle bbb -> ccc -> bbb lies between you and out, the cycle eee -> fff -> eee not.
+        let input = "you: aaa\naaa: bbb\nbbb: ccc\nccc: bbb out\neee: fff\nfff: eee out";
+        let cycle = ["bbb", "ccc"].map(String::from).to_vec();
+        assert_eq!(Day11::solve_part1(input), Ok(Err(CycleError { cycle })));
+
+        let input = "you: aaa\naaa: out\neee: fff\nfff: eee out";
+        assert_eq!(Day11::solve_part1(input), Ok(Ok(1)));
+
+        let input = "you: aaa\naaa: you out";
+        let cycle = ["aaa", "you"].map(String::from).to_vec();
+        assert_eq!(Day11::solve_part1(input), Ok(Err(CycleError { cycle })));
+
+        // The runners report the cycle as an error rather than as an answer.
+        let error = puzzle(Day11::DAY)
+            .unwrap()
+            .solve(input, Part::Part1, &Params::of::<Day11>())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::Part {
+                day: 11,
+                part: Part::Part1,
+                message: "cycle between the start and the end: aaa -> you -> aaa".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_waypoints_in_any_order() {
+        let map = Day11::parse(SHARED_INPUT_2.trim()).unwrap();
+        for waypoints in [["fft", "dac"], ["dac", "fft"]] {
+            assert_eq!(count_paths_through(&map, "svr", "out", &waypoints), Ok(2));
+        }
+        assert_eq!(count_paths_through(&map, "svr", "out", &["hub"]), Ok(4));
+        assert_eq!(
+            count_paths_through(&map, "svr", "out", &["dac", "hub"]),
+            Ok(0)
+        );
+
+        let params = Params::of::<Day11>().with("waypoints", "dac,fft");
+        assert_eq!(
+            Day11::solve_part2_with(SHARED_INPUT_2.trim(), &params),
+            Ok(Ok(2))
+        );
+    }
+}
@@ -0,0 +1,105 @@
+/// Partition of the elements `0..len` into disjoint components, merged with [`DisjointSet::union`].
+/// Uses path compression and union by size.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Create `len` singleton components.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the component of `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Merge the components of `a` and `b`, returning whether they were distinct.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = match self.size[a] < self.size[b] {
+            true => (b, a),
+            false => (a, b),
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of components.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Number of elements in the component of `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Sizes of all components, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjointSet;
+
+    #[test]
+    fn test_union() {
+        let mut set = DisjointSet::new(6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert_eq!(set.components(), 3);
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.size(2), 4);
+
+        let mut sizes = set.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 4]);
+    }
+}
@@ -0,0 +1,114 @@
+/// Solutions of a linear system `A x = b` over GF(2), with up to 64 equations and 64 unknowns.
+/// Each column of `A`, the right-hand side `b` and each solution `x` are bit masks: bit `i` of a
+/// column or of `b` is equation `i`, and bit `j` of `x` is unknown `j`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solutions {
+    /// The solution with all free unknowns zero.
+    pub particular: u64,
+    /// Basis of the null space of `A`, one vector per free unknown.
+    pub null_space: Vec<u64>,
+}
+
+impl Solutions {
+    /// Solve the system by Gaussian elimination, or return `None` if it is inconsistent.
+    pub fn new(columns: &[u64], rhs: u64) -> Option<Self> {
+        assert!(columns.len() <= 64, "More than 64 unknowns");
+
+        // Row `i` holds the coefficients of equation `i` as a mask over the unknowns, and its
+        // right-hand side.
+        let mut rows: Vec<(u64, bool)> = (0..64)
+            .map(|i| {
+                let coefficients = columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, column)| *column >> i & 1 == 1)
+                    .fold(0, |mask, (j, _)| mask | 1 << j);
+                (coefficients, rhs >> i & 1 == 1)
+            })
+            .filter(|row| *row != (0, false))
+            .collect();
+
+        // Reduce to reduced row echelon form, remembering the pivot unknown of each row.
+        let mut pivots = Vec::new();
+        for unknown in 0..columns.len() {
+            let bit = 1 << unknown;
+            let Some(index) = (pivots.len()..rows.len()).find(|&i| rows[i].0 & bit != 0) else {
+                continue;
+            };
+            rows.swap(pivots.len(), index);
+            let pivot = rows[pivots.len()];
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != pivots.len() && row.0 & bit != 0 {
+                    *row = (row.0 ^ pivot.0, row.1 ^ pivot.1);
+                }
+            }
+            pivots.push(unknown);
+        }
+
+        // Any remaining row reads `0 = b`.
+        if rows[pivots.len()..].iter().any(|(_, rhs)| *rhs) {
+            return None;
+        }
+
+        let particular = pivots
+            .iter()
+            .zip(&rows)
+            .filter(|(_, (_, rhs))| *rhs)
+            .fold(0, |x, (unknown, _)| x | 1 << unknown);
+        let null_space = (0..columns.len())
+            .filter(|unknown| !pivots.contains(unknown))
+            .map(|free| {
+                pivots
+                    .iter()
+                    .zip(&rows)
+                    .filter(|(_, (coefficients, _))| coefficients >> free & 1 == 1)
+                    .fold(1 << free, |x, (unknown, _)| x | 1 << unknown)
+            })
+            .collect();
+
+        Some(Solutions {
+            particular,
+            null_space,
+        })
+    }
+
+    /// All solutions, by adding every combination of the null space basis to the particular one.
+    /// There are `2^k` of them for a null space of dimension `k`, which must be below 64.
+    pub fn iter(&self) -> impl Iterator<Item = u64> {
+        assert!(self.null_space.len() < 64, "Null space of 64 dimensions");
+        (0..1u64 << self.null_space.len()).map(|combination| {
+            self.null_space
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| combination >> i & 1 == 1)
+                .fold(self.particular, |x, (_, vector)| x ^ vector)
+        })
+    }
+
+    /// The solution with the fewest unknowns set.
+    pub fn min_weight(&self) -> u64 {
+        self.iter()
+            .min_by_key(|x| x.count_ones())
+            .expect("A consistent system has a solution")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solutions;
+
+    #[test]
+    fn test_solve() {
+        // x0 + x1 = 1, x1 + x2 = 0, with x2 free.
+        let columns = [0b01, 0b11, 0b10];
+        let solutions = Solutions::new(&columns, 0b01).unwrap();
+        assert_eq!(solutions.null_space.len(), 1);
+        let mut all: Vec<u64> = solutions.iter().collect();
+        all.sort();
+        assert_eq!(all, [0b001, 0b110]);
+        assert_eq!(solutions.min_weight(), 0b001);
+
+        // x0 = 1 and x0 = 0.
+        assert_eq!(Solutions::new(&[0b11], 0b01), None);
+    }
+}
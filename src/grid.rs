@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{ParseError, Source, chars};
+
+/// Position in a grid as `(row, column)`.
+pub type Coord = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid stored row by row in a flat vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells listed row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a grid from its rows, or `None` if the rows are not of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// Parse the input of `source` as a grid with one character per cell, e.g. a map of `.` and
+    /// `#`. `cell` receives each character along with its span for error reporting.
+    pub fn parse(
+        source: &Source,
+        mut cell: impl FnMut(char, &str) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let width = source.rectangle()?;
+        let cells = source
+            .input()
+            .lines()
+            .flat_map(chars)
+            .map(|(c, span)| cell(c, span))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(Grid::new(width, cells.len() / width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Coord) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Coord) -> Option<&mut T> {
+        (row < self.height && column < self.width)
+            .then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Coord> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, position: Coord) -> impl Iterator<Item = Coord> + use<T> {
+        self.neighbours(position, &OFFSETS_4)
+    }
+
+    /// Positions of the up to 8 orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, position: Coord) -> impl Iterator<Item = Coord> + use<T> {
+        self.neighbours(position, &OFFSETS_8)
+    }
+
+    fn neighbours(
+        &self,
+        (row, column): Coord,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Coord> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(d_row, d_column)| {
+            let row = row.checked_add_signed(*d_row).filter(|row| *row < height)?;
+            let column = column
+                .checked_add_signed(*d_column)
+                .filter(|column| *column < width)?;
+            Some((row, column))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Swap rows and columns, so that `transpose()[(i, j)] == self[(j, i)]`.
+    pub fn transpose(&self) -> Self {
+        Grid::new(
+            self.height,
+            self.width,
+            self.columns().flatten().cloned().collect(),
+        )
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Coord) -> &Self::Output {
+        self.get(position).expect("Position out of grid")
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, position: Coord) -> &mut Self::Output {
+        self.get_mut(position).expect("Position out of grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::parse::Source;
+
+    const INPUT: &str = "#..\n.#.";
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(&Source::new(0, input), |c, _| Ok(c)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_display() {
+        let grid = parse(INPUT);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 1)), Some(&'#'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(grid.transpose().to_string(), "#.\n.#\n..");
+
+        let error = Grid::parse(&Source::new(0, "#..\n.#"), |c, _| Ok(c)).unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = parse(INPUT);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 2)).collect::<Vec<_>>(),
+            [(0, 1), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), ".#");
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+/// Why a system has no optimal solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// No solution satisfies the constraints.
+    Infeasible,
+    /// The search for an optimal solution is unbounded.
+    Unbounded,
+    /// The solver failed for another reason.
+    Failed(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Infeasible => write!(f, "infeasible"),
+            SolveError::Unbounded => write!(f, "unbounded"),
+            SolveError::Failed(message) => write!(f, "solver failure: {message}"),
+        }
+    }
+}
+
+/// Minimise `x_1 + ... + x_n` over non-negative integers `x` subject to `A x = b`, where `A` is given
+/// by rows.
+///
+/// The system is reduced by fraction-free Gaussian elimination, leaving the pivot unknowns as
+/// functions of the free unknowns. The free unknowns are then enumerated by branch and bound, each
+/// between zero and the bound given by an equation with non-negative coefficients, as `a x <= b`
+/// for any unknown `x` with coefficient `a > 0` in such an equation. An unknown which appears in
+/// no equation is zero. The search is unbounded if any other free unknown has no such bound.
+pub fn minimise_sum(matrix: &[Vec<i64>], rhs: &[i64]) -> Result<Vec<u64>, SolveError> {
+    let unknowns = matrix.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<i128>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, b)| row.iter().chain([b]).map(|&a| a as i128).collect())
+        .collect();
+
+    let mut bounds: Vec<Option<u64>> = (0..unknowns)
+        .map(|j| matrix.iter().all(|row| row[j] == 0).then_some(0))
+        .collect();
+    for row in rows
+        .iter()
+        .filter(|row| row[..unknowns].iter().all(|a| *a >= 0))
+    {
+        let b = row[unknowns];
+        if b < 0 {
+            return Err(SolveError::Infeasible);
+        }
+        for (bound, a) in bounds.iter_mut().zip(&row[..unknowns]) {
+            if *a > 0 {
+                let limit = (b / a) as u64;
+                *bound = Some(bound.map_or(limit, |bound| bound.min(limit)));
+            }
+        }
+    }
+
+    let mut pivots = Vec::new();
+    for column in 0..unknowns {
+        let r = pivots.len();
+        let Some(index) = (r..rows.len()).find(|&i| rows[i][column] != 0) else {
+            continue;
+        };
+        rows.swap(r, index);
+        let pivot = rows[r].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if i != r && factor != 0 {
+                for (a, p) in row.iter_mut().zip(&pivot) {
+                    *a = *a * pivot[column] - p * factor;
+                }
+                normalise(row);
+            }
+        }
+        pivots.push(column);
+    }
+
+    // Any remaining row reads `0 = b`.
+    if rows[pivots.len()..].iter().any(|row| row[unknowns] != 0) {
+        return Err(SolveError::Infeasible);
+    }
+    rows.truncate(pivots.len());
+    for (row, &pivot) in rows.iter_mut().zip(&pivots) {
+        if row[pivot] < 0 {
+            row.iter_mut().for_each(|a| *a = -*a);
+        }
+    }
+
+    let free: Vec<usize> = (0..unknowns).filter(|j| !pivots.contains(j)).collect();
+    let bounds = free
+        .iter()
+        .map(|&j| bounds[j].ok_or(SolveError::Unbounded))
+        .collect::<Result<_, _>>()?;
+
+    // Each pivot row is solved as soon as the free unknowns it depends on are assigned.
+    let mut ready = vec![Vec::new(); free.len() + 1];
+    for (r, row) in rows.iter().enumerate() {
+        let level = free
+            .iter()
+            .rposition(|&j| row[j] != 0)
+            .map_or(0, |index| index + 1);
+        ready[level].push(r);
+    }
+
+    let mut search = Search {
+        rows,
+        pivots,
+        free,
+        bounds,
+        ready,
+        x: vec![0; unknowns],
+        best: None,
+        best_cost: u64::MAX,
+    };
+    search.visit(0, 0);
+    search.best.ok_or(SolveError::Infeasible)
+}
+
+/// Divide a row by the greatest common divisor of its entries.
+fn normalise(row: &mut [i128]) {
+    let gcd = row.iter().fold(0, |gcd, a| {
+        let (mut a, mut b) = (gcd, a.abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    });
+    if gcd > 1 {
+        row.iter_mut().for_each(|a| *a /= gcd);
+    }
+}
+
+struct Search {
+    /// Reduced rows, each with a positive coefficient of its pivot unknown.
+    rows: Vec<Vec<i128>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    bounds: Vec<u64>,
+    /// Rows solved once the first `level` free unknowns are assigned.
+    ready: Vec<Vec<usize>>,
+    x: Vec<u64>,
+    best: Option<Vec<u64>>,
+    best_cost: u64,
+}
+
+impl Search {
+    /// Solve the pivot unknowns determined by the first `level` free unknowns, then branch on the
+    /// next free unknown. `cost` is the sum of the unknowns assigned so far.
+    fn visit(&mut self, level: usize, mut cost: u64) {
+        let unknowns = self.x.len();
+        for &r in &self.ready[level] {
+            let row = &self.rows[r];
+            let value = row[unknowns]
+                - self
+                    .free
+                    .iter()
+                    .map(|&j| row[j] * self.x[j] as i128)
+                    .sum::<i128>();
+            let pivot = self.pivots[r];
+            if value < 0 || value % row[pivot] != 0 {
+                return;
+            }
+            self.x[pivot] = (value / row[pivot]) as u64;
+            cost += self.x[pivot];
+        }
+        if cost >= self.best_cost {
+            return;
+        }
+
+        let Some(&unknown) = self.free.get(level) else {
+            self.best = Some(self.x.clone());
+            self.best_cost = cost;
+            return;
+        };
+        for value in 0..=self.bounds[level] {
+            if cost + value >= self.best_cost {
+                break;
+            }
+            self.x[unknown] = value;
+            self.visit(level + 1, cost + value);
+        }
+        self.x[unknown] = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SolveError, minimise_sum};
+
+    #[test]
+    fn test_minimise_sum() {
+        // x0 + x1 = 4, x1 + x2 = 6: the minimum 6 takes x1 = 4.
+        let matrix = [vec![1, 1, 0], vec![0, 1, 1]];
+        assert_eq!(minimise_sum(&matrix, &[4, 6]), Ok(vec![0, 4, 2]));
+
+        // 2 x0 = 3 has no integer solution.
+        assert_eq!(minimise_sum(&[vec![2]], &[3]), Err(SolveError::Infeasible));
+        // x0 + x1 = 1, x0 + x1 = 2 is inconsistent.
+        assert_eq!(
+            minimise_sum(&[vec![1, 1], vec![1, 1]], &[1, 2]),
+            Err(SolveError::Infeasible)
+        );
+        // x0 - x1 = 1 has no bound on x1.
+        assert_eq!(
+            minimise_sum(&[vec![1, -1]], &[1]),
+            Err(SolveError::Unbounded)
+        );
+    }
+}
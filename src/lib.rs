@@ -0,0 +1,301 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use bench::BenchReport;
+use params::{Param, Params};
+use parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod day00;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod disjoint_set;
+pub mod gf2;
+pub mod grid;
+pub mod ilp;
+pub mod model;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+pub mod range_set;
+
+/// A solution to the puzzle of one day.
+///
+/// The input is parsed once by `parse`, and both parts work on the parsed result, so that tooling
+/// can call (and time) each phase separately. Values that the puzzle fixes but that are worth
+/// varying, e.g. for running the examples, are declared in `PARAMS` and passed to both parts.
+pub trait Solution {
+    const DAY: u8;
+    const PARAMS: &'static [Param] = &[];
+
+    type Parsed;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(input: &Self::Parsed, params: &Params) -> Self::Answer1;
+
+    fn part2(input: &Self::Parsed, params: &Params) -> Self::Answer2;
+
+    fn solve_part1(input: &str) -> Result<Self::Answer1, ParseError> {
+        Self::solve_part1_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn solve_part2(input: &str) -> Result<Self::Answer2, ParseError> {
+        Self::solve_part2_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn solve_part1_with(input: &str, params: &Params) -> Result<Self::Answer1, ParseError> {
+        Ok(Self::part1(&Self::parse(input)?, params))
+    }
+
+    fn solve_part2_with(input: &str, params: &Params) -> Result<Self::Answer2, ParseError> {
+        Ok(Self::part2(&Self::parse(input)?, params))
+    }
+}
+
+/// Answer of a part, as printed by the runners.
+///
+/// A part may have no answer even on a well-formed input, e.g. when a count is infinite. Its answer
+/// is then the reason, which the runners report as an error instead of printing it as an answer.
+pub trait Answer {
+    fn answer(&self) -> Result<String, String>;
+}
+
+macro_rules! answer_by_display {
+    ($($answer:ty),*) => {
+        $(
+            impl Answer for $answer {
+                fn answer(&self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_by_display!(u32, u64, u128, usize, i32, i64, i128, String);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn answer(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.answer(),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
+
+/// Why a day gave no answer on an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is malformed.
+    Parse(ParseError),
+    /// A part has no answer on the input, for the given reason.
+    Part {
+        day: u8,
+        part: Part,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Render the error for the user, quoting the offending line of `input` for a parse error.
+    /// `origin` names where the input comes from, e.g. its file path.
+    pub fn report(&self, input: &str, origin: &str) -> String {
+        match self {
+            Error::Parse(error) => error.report(input, origin),
+            Error::Part { day, part, message } => {
+                format!("day {day:02}: no answer to {part} on {origin}: {message}")
+            }
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Part { day, part, message } => write!(f, "day {day:02}, {part}: {message}"),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Part{}", self.number())
+    }
+}
+
+/// Type-erased handle to the `Solution` of one day, so that a runner can pick a day at runtime.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u8,
+    params: &'static [Param],
+    solve: fn(&str, Part, &Params) -> Result<String, Error>,
+    bench: fn(&str, &str, usize, &Params) -> Result<BenchReport, ParseError>,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            params: S::PARAMS,
+            solve: solve::<S>,
+            bench: BenchReport::measure::<S>,
+        }
+    }
+
+    /// Runtime parameters declared by the day.
+    pub fn params(&self) -> &'static [Param] {
+        self.params
+    }
+
+    /// Parse the input and return the answer of the given part.
+    pub fn solve(&self, input: &str, part: Part, params: &Params) -> Result<String, Error> {
+        (self.solve)(input, part, params)
+    }
+
+    /// Time each phase separately over the given number of iterations.
+    pub fn bench(
+        &self,
+        origin: &str,
+        input: &str,
+        iterations: usize,
+        params: &Params,
+    ) -> Result<BenchReport, ParseError> {
+        (self.bench)(origin, input, iterations, params)
+    }
+
+    /// Default location of the puzzle input, e.g. `input/day05.txt`.
+    pub fn input_path(&self) -> PathBuf {
+        format!("input/day{:02}.txt", self.day).into()
+    }
+
+    /// Location of the recorded answers, e.g. `answers/day05.toml`.
+    pub fn answers_path(&self) -> PathBuf {
+        format!("answers/day{:02}.toml", self.day).into()
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Part, params: &Params) -> Result<String, Error> {
+    let answer = match part {
+        Part::Part1 => S::solve_part1_with(input, params)?.answer(),
+        Part::Part2 => S::solve_part2_with(input, params)?.answer(),
+    };
+    answer.map_err(|message| Error::Part {
+        day: S::DAY,
+        part,
+        message,
+    })
+}
+
+/// All implemented days, ordered by day.
+pub const PUZZLES: [Puzzle; 13] = [
+    Puzzle::of::<day00::Day00>(),
+    Puzzle::of::<day01::Day01>(),
+    Puzzle::of::<day02::Day02>(),
+    Puzzle::of::<day03::Day03>(),
+    Puzzle::of::<day04::Day04>(),
+    Puzzle::of::<day05::Day05>(),
+    Puzzle::of::<day06::Day06>(),
+    Puzzle::of::<day07::Day07>(),
+    Puzzle::of::<day08::Day08>(),
+    Puzzle::of::<day09::Day09>(),
+    Puzzle::of::<day10::Day10>(),
+    Puzzle::of::<day11::Day11>(),
+    Puzzle::of::<day12::Day12>(),
+];
+
+/// Look up the puzzle of the given day.
+pub fn puzzle(day: u8) -> Option<Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day).copied()
+}
+
+/// Input path standing for the standard input.
+pub const STDIN: &str = "-";
+
+/// Read a puzzle input from a file, or from the standard input if the path is `-`.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN) {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Name of an input path for labelling answers and errors.
+pub fn input_name(path: &Path) -> String {
+    if path == Path::new(STDIN) {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Entry point shared by the binaries in `src/bin`, taking a part and one or more inputs, e.g.
+/// `day05 part1 input/day05.txt -`. Answers are labelled by input when there are several.
+pub fn run<S: Solution>() {
+    let part = match env::args().nth(1).expect("Missing <part>").as_str() {
+        "part1" => Part::Part1,
+        "part2" => Part::Part2,
+        part => panic!("Unknown part: {}", part),
+    };
+    let filenames: Vec<PathBuf> = env::args().skip(2).map(PathBuf::from).collect();
+    assert!(!filenames.is_empty(), "Missing <input>");
+
+    for filename in &filenames {
+        let input = read_input(filename).expect("Unable to read file");
+        let label = match filenames.len() {
+            1 => String::new(),
+            _ => format!("{} ", input_name(filename)),
+        };
+
+        match solve::<S>(&input, part, &Params::defaults(S::PARAMS)) {
+            Ok(answer) => println!("{label}{part}: {answer}"),
+            Err(error) => {
+                eprintln!("error: {}", error.report(&input, &input_name(filename)));
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/// Integer linear program over non-negative integer variables, minimising a linear objective
+/// subject to equality constraints, which can be written in the CPLEX LP or MPS formats to be fed to
+/// other solvers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Model {
+    pub name: String,
+    pub variables: Vec<String>,
+    /// Coefficients of the objective, by variable index.
+    pub objective: Vec<(usize, i64)>,
+    pub constraints: Vec<Constraint>,
+}
+
+/// Equality constraint `terms = rhs`, with the terms as coefficients by variable index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub name: String,
+    pub terms: Vec<(usize, i64)>,
+    pub rhs: i64,
+}
+
+impl Model {
+    fn expression(&self, terms: &[(usize, i64)]) -> String {
+        if terms.is_empty() {
+            return match self.variables.first() {
+                Some(variable) => format!("0 {variable}"),
+                None => "0".to_string(),
+            };
+        }
+
+        let mut expression = String::new();
+        for (index, &(variable, coefficient)) in terms.iter().enumerate() {
+            let sign = match (index, coefficient < 0) {
+                (0, false) => "",
+                (0, true) => "- ",
+                (_, false) => " + ",
+                (_, true) => " - ",
+            };
+            let magnitude = match coefficient.unsigned_abs() {
+                1 => String::new(),
+                magnitude => format!("{magnitude} "),
+            };
+            expression += &format!("{sign}{magnitude}{}", self.variables[variable]);
+        }
+        expression
+    }
+
+    pub fn to_lp(&self) -> String {
+        let mut lp = format!(
+            "\\ {}\nMinimize\n obj: {}\nSubject To\n",
+            self.name,
+            self.expression(&self.objective)
+        );
+        for constraint in &self.constraints {
+            lp += &format!(
+                " {}: {} = {}\n",
+                constraint.name,
+                self.expression(&constraint.terms),
+                constraint.rhs
+            );
+        }
+        lp += "Bounds\n";
+        for variable in &self.variables {
+            lp += &format!(" {variable} >= 0\n");
+        }
+        if !self.variables.is_empty() {
+            lp += &format!("General\n {}\n", self.variables.join(" "));
+        }
+        lp + "End\n"
+    }
+
+    pub fn to_mps(&self) -> String {
+        let mut mps = format!("NAME {}\nROWS\n N obj\n", self.name);
+        for constraint in &self.constraints {
+            mps += &format!(" E {}\n", constraint.name);
+        }
+
+        mps += "COLUMNS\n    MARKER 'MARKER' 'INTORG'\n";
+        for (index, variable) in self.variables.iter().enumerate() {
+            let rows = [("obj", &self.objective)].into_iter().chain(
+                self.constraints
+                    .iter()
+                    .map(|constraint| (constraint.name.as_str(), &constraint.terms)),
+            );
+            for (row, terms) in rows {
+                for (_, coefficient) in terms.iter().filter(|(v, _)| *v == index) {
+                    mps += &format!("    {variable} {row} {coefficient}\n");
+                }
+            }
+        }
+        mps += "    MARKER 'MARKER' 'INTEND'\nRHS\n";
+        for constraint in self.constraints.iter().filter(|c| c.rhs != 0) {
+            mps += &format!("    RHS {} {}\n", constraint.name, constraint.rhs);
+        }
+
+        // Integer variables without bounds may default to binary, so the bounds are explicit.
+        mps += "BOUNDS\n";
+        for variable in &self.variables {
+            mps += &format!(" PL BND {variable}\n");
+        }
+        mps + "ENDATA\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Constraint, Model};
+
+    #[test]
+    fn test_formats() {
+        let model = Model {
+            name: "example".to_string(),
+            variables: vec!["x0".to_string(), "m0".to_string()],
+            objective: vec![(0, 1)],
+            constraints: vec![Constraint {
+                name: "c0".to_string(),
+                terms: vec![(0, 1), (1, -2)],
+                rhs: 1,
+            }],
+        };
+
+        assert_eq!(
+            model.to_lp(),
+            "\\ example\nMinimize\n obj: x0\nSubject To\n c0: x0 - 2 m0 = 1\n\
+             Bounds\n x0 >= 0\n m0 >= 0\nGeneral\n x0 m0\nEnd\n"
+        );
+        assert_eq!(
+            model.to_mps(),
+            "NAME example\nROWS\n N obj\n E c0\nCOLUMNS\n    MARKER 'MARKER' 'INTORG'\n\
+             \x20   x0 obj 1\n    x0 c0 1\n    m0 c0 -2\n    MARKER 'MARKER' 'INTEND'\n\
+             RHS\n    RHS c0 1\nBOUNDS\n PL BND x0\n PL BND m0\nENDATA\n"
+        );
+    }
+
+    #[test]
+    fn test_no_variables() {
+        let model = Model {
+            name: "empty".to_string(),
+            variables: Vec::new(),
+            objective: Vec::new(),
+            constraints: vec![Constraint {
+                name: "c0".to_string(),
+                terms: Vec::new(),
+                rhs: 1,
+            }],
+        };
+
+        assert_eq!(
+            model.to_lp(),
+            "\\ empty\nMinimize\n obj: 0\nSubject To\n c0: 0 = 1\nBounds\nEnd\n"
+        );
+        assert!(
+            model
+                .to_mps()
+                .ends_with("RHS\n    RHS c0 1\nBOUNDS\nENDATA\n")
+        );
+    }
+}
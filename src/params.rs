@@ -0,0 +1,191 @@
+use std::{collections::BTreeMap, fmt::Debug, str::FromStr};
+
+use crate::Solution;
+
+/// Declaration of a runtime parameter of a day, e.g. the number of connections of day 08.
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+    pub kind: Kind,
+}
+
+/// Values accepted by a parameter, checked before any part runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A non-negative integer.
+    Number,
+    /// Any text, e.g. a device name.
+    Text,
+    /// One of the listed values, e.g. the name of a metric.
+    Choice(&'static [&'static str]),
+}
+
+impl Param {
+    pub const fn number(
+        name: &'static str,
+        default: &'static str,
+        description: &'static str,
+    ) -> Self {
+        Param {
+            name,
+            default,
+            description,
+            kind: Kind::Number,
+        }
+    }
+
+    pub const fn text(
+        name: &'static str,
+        default: &'static str,
+        description: &'static str,
+    ) -> Self {
+        Param {
+            name,
+            default,
+            description,
+            kind: Kind::Text,
+        }
+    }
+
+    pub const fn choice(
+        name: &'static str,
+        default: &'static str,
+        choices: &'static [&'static str],
+        description: &'static str,
+    ) -> Self {
+        Param {
+            name,
+            default,
+            description,
+            kind: Kind::Choice(choices),
+        }
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        match self.kind {
+            Kind::Number if value.parse::<u64>().is_err() => Err(format!(
+                "Invalid value `{value}` of parameter `{}`, expected a number",
+                self.name
+            )),
+            Kind::Choice(choices) if !choices.contains(&value) => Err(format!(
+                "Invalid value `{value}` of parameter `{}`, expected one of {}",
+                self.name,
+                choices.join(", ")
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Values of the parameters of a day, the defaults overridden by the user.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// The default values of the parameters declared by `S`.
+    pub fn of<S: Solution>() -> Self {
+        Self::defaults(S::PARAMS)
+    }
+
+    pub fn defaults(declared: &[Param]) -> Self {
+        Params {
+            values: declared
+                .iter()
+                .map(|param| (param.name.to_string(), param.default.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Apply overrides to the defaults, rejecting parameters which are not declared.
+    pub fn with_overrides(
+        declared: &[Param],
+        overrides: &[(String, String)],
+    ) -> Result<Self, String> {
+        let mut params = Self::defaults(declared);
+        for (name, value) in overrides {
+            match declared.iter().find(|param| param.name == name) {
+                Some(param) => {
+                    param.check(value)?;
+                    params.values.insert(name.clone(), value.clone());
+                }
+                None => {
+                    let available = match declared.is_empty() {
+                        true => "none".to_string(),
+                        false => declared
+                            .iter()
+                            .map(|param| format!("{} ({})", param.name, param.description))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    };
+                    return Err(format!(
+                        "Unknown parameter `{name}`, available parameters: {available}"
+                    ));
+                }
+            }
+        }
+        Ok(params)
+    }
+
+    /// Override one declared parameter.
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        let current = self
+            .values
+            .get_mut(name)
+            .unwrap_or_else(|| panic!("Unknown parameter `{name}`"));
+        *current = value.to_string();
+        self
+    }
+
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        let value = self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Unknown parameter `{name}`"));
+        value.parse().unwrap_or_else(|error| {
+            panic!("Invalid value `{value}` of parameter `{name}`: {error:?}")
+        })
+    }
+}
+
+/// Parse a `name=value` assignment of a parameter.
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+    assignment
+        .split_once("=")
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .ok_or(format!("expected `name=value`, found `{assignment}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, Params, parse_assignment};
+
+    const PARAMS: &[Param] = &[Param::number(
+        "connections",
+        "1000",
+        "number of connections",
+    )];
+
+    #[test]
+    fn test_overrides() {
+        assert_eq!(Params::defaults(PARAMS).get::<usize>("connections"), 1000);
+
+        let overrides = [parse_assignment("connections=10").unwrap()];
+        let params = Params::with_overrides(PARAMS, &overrides).unwrap();
+        assert_eq!(params.get::<usize>("connections"), 10);
+        assert_eq!(params, Params::defaults(PARAMS).with("connections", 10));
+
+        let overrides = [parse_assignment("junctions=10").unwrap()];
+        assert!(Params::with_overrides(PARAMS, &overrides).is_err());
+        let overrides = [parse_assignment("connections=ten").unwrap()];
+        assert!(Params::with_overrides(PARAMS, &overrides).is_err());
+        assert!(parse_assignment("connections").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Error raised on malformed puzzle input, locating the offending text in the input.
+///
+/// Lines and columns are 1-based, and columns count characters rather than bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Render the error as a caret-style diagnostic, quoting the offending line of `input`.
+    /// `origin` names where the input comes from, e.g. its file path.
+    pub fn report(&self, input: &str, origin: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let available = source_line.chars().count().saturating_sub(self.column - 1);
+        let width = self.text.chars().count().min(available).max(1);
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "day {:02}: {}\n{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.day,
+            self.message,
+            origin,
+            self.line,
+            self.column,
+            self.line,
+            source_line,
+            " ".repeat(self.column - 1),
+            "^".repeat(width),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// The puzzle input of a day, used to build `ParseError`s pointing into it.
+///
+/// Every `span` passed to its methods must be a slice of the input, so that its position can be
+/// recovered from the address of the slice.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Source { day, input }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn error(&self, span: &str, message: impl Into<String>) -> ParseError {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(self.input.as_ptr() as usize)
+            .filter(|offset| *offset <= self.input.len())
+            .unwrap_or(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: span.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing right after the end of `span`, for something missing there.
+    pub fn error_after(&self, span: &str, message: impl Into<String>) -> ParseError {
+        self.error(&span[span.len()..], message)
+    }
+
+    /// Check that the lines of the input form a non-empty rectangle of characters, and return its
+    /// width.
+    pub fn rectangle(&self) -> Result<usize, ParseError> {
+        let width = match self.input.lines().next() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => return Err(self.error(self.input, "expected a non-empty grid")),
+        };
+        match self
+            .input
+            .lines()
+            .find(|line| line.chars().count() != width)
+        {
+            Some(line) => Err(self.error(line, format!("expected a row of {width} characters"))),
+            None => Ok(width),
+        }
+    }
+
+    /// Strip the delimiters `open` and `close` around `span`, e.g. the brackets of `[.##.]`.
+    pub fn enclosed<'b>(
+        &self,
+        span: &'b str,
+        open: char,
+        close: char,
+    ) -> Result<&'b str, ParseError> {
+        span.strip_prefix(open)
+            .and_then(|span| span.strip_suffix(close))
+            .ok_or_else(|| self.error(span, format!("expected `{open}...{close}`")))
+    }
+
+    pub fn number<T: FromStr>(&self, span: &str) -> Result<T, ParseError> {
+        span.parse()
+            .map_err(|_| self.error(span, "expected a number"))
+    }
+
+    pub fn split_once<'b>(
+        &self,
+        span: &'b str,
+        delimiter: &str,
+    ) -> Result<(&'b str, &'b str), ParseError> {
+        span.split_once(delimiter)
+            .ok_or_else(|| self.error(span, format!("missing `{}`", delimiter.escape_debug())))
+    }
+}
+
+/// Iterate over the characters of `line` along with their slices, so that an offending character
+/// can be passed as span to `Source::error`.
+pub fn chars(line: &str) -> impl Iterator<Item = (char, &str)> {
+    line.char_indices()
+        .map(|(index, c)| (c, &line[index..index + c.len_utf8()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+
+    const INPUT: &str = "R12\nL3\nX42";
+
+    #[test]
+    fn test_error_position() {
+        let source = Source::new(1, INPUT);
+        let error = source.error(&INPUT[8..], "unknown rotation");
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.text, "42");
+        assert_eq!(
+            error.to_string(),
+            "day 01, line 3, column 2: unknown rotation `42`"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let source = Source::new(1, INPUT);
+        let error = source.number::<i32>(&INPUT[7..]).unwrap_err();
+        assert_eq!(
+            error.report(INPUT, "input/day01.txt"),
+            r#"day 01: expected a number
+ --> input/day01.txt:3:1
+  |
+3 | X42
+  | ^^^"#
+        );
+    }
+}
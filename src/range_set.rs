@@ -0,0 +1,166 @@
+use std::ops::RangeInclusive;
+
+/// Set of integers stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// Add every value of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .ranges
+            .partition_point(|(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|(s, _)| *s <= end.saturating_add(1));
+
+        let merged = match first < last {
+            true => (
+                start.min(self.ranges[first].0),
+                end.max(self.ranges[last - 1].1),
+            ),
+            false => (start, end),
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove every value of `range`, splitting the ranges it cuts through.
+    pub fn remove(&mut self, range: RangeInclusive<u64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|(_, e)| *e < start);
+        let last = self.ranges.partition_point(|(s, _)| *s <= end);
+        if first == last {
+            return;
+        }
+
+        let (head, tail) = (self.ranges[first].0, self.ranges[last - 1].1);
+        let remaining = [
+            (head < start).then(|| (head, start - 1)),
+            (tail > end).then(|| (end + 1, tail)),
+        ];
+        self.ranges
+            .splice(first..last, remaining.into_iter().flatten());
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let index = self.ranges.partition_point(|(_, end)| *end < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|(start, _)| *start <= value)
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        other.iter().for_each(|range| union.insert(range));
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            match a_end < b_end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut difference = self.clone();
+        other.iter().for_each(|range| difference.remove(range));
+        difference
+    }
+
+    /// The values of `bounds` which are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> RangeSet {
+        RangeSet::from_iter([bounds]).difference(self)
+    }
+
+    /// Number of values in the set. Overflows if the set holds all of `u64`.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges of the set in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = RangeSet::from_iter([3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=5, 10..=20]);
+        assert_eq!(set.len(), 14);
+        assert!(set.contains(5) && set.contains(17) && !set.contains(8));
+
+        set.insert(6..=9);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=20]);
+        set.remove(8..=11);
+        set.remove(0..=3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [4..=7, 12..=20]);
+
+        set.insert(u64::MAX - 1..=u64::MAX);
+        set.remove(u64::MAX..=u64::MAX);
+        assert!(set.contains(u64::MAX - 1) && !set.contains(u64::MAX));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = RangeSet::from_iter([0..=9, 20..=29]);
+        let b = RangeSet::from_iter([5..=24]);
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [0..=29]);
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            [5..=9, 20..=24]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            [0..=4, 25..=29]
+        );
+        assert_eq!(
+            a.complement(0..=39).iter().collect::<Vec<_>>(),
+            [10..=19, 30..=39]
+        );
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+}